@@ -0,0 +1,123 @@
+//! A compact binary format for word lists.
+//!
+//! The format is three sections – adjectives, adverbs, then names – each of
+//! which is:
+//!
+//!   * the number of words, as a little-endian `u32`, followed by
+//!   * each word, as its length in bytes as a little-endian `u32` followed by
+//!     that many bytes of UTF-8.
+//!
+//! There is no header, padding, or trailer. Loading borrows each word
+//! directly from the input, so no words are copied.
+
+use alloc::vec::Vec;
+use core::{convert::TryInto, fmt, str};
+
+use crate::{AdverbPolicy, Petnames, Words};
+
+/// Errors from [`Petnames::from_binary`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinaryError {
+    /// The input ended part way through a section.
+    Truncated,
+    /// A word is not valid UTF-8.
+    InvalidUtf8,
+    /// There are bytes after the last section.
+    TrailingBytes,
+}
+
+impl fmt::Display for BinaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            BinaryError::Truncated => write!(f, "word lists are truncated"),
+            BinaryError::InvalidUtf8 => write!(f, "word is not valid UTF-8"),
+            BinaryError::TrailingBytes => write!(f, "unexpected bytes after word lists"),
+        }
+    }
+}
+
+impl<'a> Petnames<'a> {
+    /// Constructs a new `Petnames` from word lists in the [binary
+    /// format][`crate::binary`], as written by
+    /// [`to_binary`][`Petnames::to_binary`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let petnames = petname::Petnames::init("happy", "very", "cat");
+    /// let bytes = petnames.to_binary();
+    /// assert_eq!(Ok(petnames), petname::Petnames::from_binary(&bytes));
+    /// ```
+    ///
+    /// The adverb policy is not part of the format; it will be the default.
+    ///
+    pub fn from_binary(bytes: &'a [u8]) -> Result<Self, BinaryError> {
+        let mut reader = Reader(bytes);
+        let petnames = Self {
+            adjectives: reader.words()?,
+            adverbs: reader.words()?,
+            names: reader.words()?,
+            adverb_policy: AdverbPolicy::default(),
+        };
+        match reader.0 {
+            [] => Ok(petnames),
+            _ => Err(BinaryError::TrailingBytes),
+        }
+    }
+
+    /// Serialize the word lists to the [binary format][`crate::binary`].
+    ///
+    /// # Panics
+    ///
+    /// If a word list has more than `u32::MAX` words, or a word is longer
+    /// than `u32::MAX` bytes.
+    ///
+    pub fn to_binary(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for list in &[&self.adjectives, &self.adverbs, &self.names] {
+            write_len(&mut bytes, list.len());
+            for word in list.iter() {
+                write_len(&mut bytes, word.len());
+                bytes.extend_from_slice(word.as_bytes());
+            }
+        }
+        bytes
+    }
+}
+
+fn write_len(bytes: &mut Vec<u8>, len: usize) {
+    let len: u32 = len.try_into().expect("too long for binary format");
+    bytes.extend_from_slice(&len.to_le_bytes());
+}
+
+/// Reads sections of the binary format from the front of a byte slice.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], BinaryError> {
+        if len > self.0.len() {
+            return Err(BinaryError::Truncated);
+        }
+        let (taken, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(taken)
+    }
+
+    fn len(&mut self) -> Result<usize, BinaryError> {
+        let mut len = [0u8; 4];
+        len.copy_from_slice(self.take(4)?);
+        Ok(u32::from_le_bytes(len) as usize)
+    }
+
+    fn words(&mut self) -> Result<Words<'a>, BinaryError> {
+        let count = self.len()?;
+        // Every word takes at least 4 bytes, so don't trust a huge count.
+        let mut words = Vec::with_capacity(count.min(self.0.len() / 4));
+        for _ in 0..count {
+            let len = self.len()?;
+            let word = self.take(len)?;
+            words.push(str::from_utf8(word).map_err(|_| BinaryError::InvalidUtf8)?);
+        }
+        Ok(words)
+    }
+}
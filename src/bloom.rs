@@ -0,0 +1,54 @@
+//! A Bloom filter, for remembering – approximately – which names have been
+//! seen; see `Petnames::iter_probably_unique`.
+
+use alloc::{vec, vec::Vec};
+use core::hash::Hasher;
+
+use crate::Fnv1a;
+
+pub(crate) struct Bloom {
+    bits: Vec<u64>,
+    hashes: u32,
+}
+
+impl Bloom {
+    /// Create a filter sized to hold `expected` items with a false-positive
+    /// rate no greater than `false_positive_rate`.
+    ///
+    /// The optimal number of hashes is log₂(1/rate), and the optimal number of
+    /// bits per item is about 1.44 times that. The logarithm is found by
+    /// halving rather than with `f64::log2`, which is not available in
+    /// `no_std`.
+    pub(crate) fn new(expected: usize, false_positive_rate: f64) -> Self {
+        let mut hashes = 1u32;
+        let mut rate = 0.5f64;
+        while rate > false_positive_rate && hashes < 64 {
+            rate /= 2.0;
+            hashes += 1;
+        }
+        let bits = (expected.max(1) as u64).saturating_mul(u64::from(hashes) * 3 / 2 + 1);
+        Self {
+            bits: vec![0; bits.div_ceil(64) as usize],
+            hashes,
+        }
+    }
+
+    /// Add `item` to the filter, returning `true` if it was – probably –
+    /// already present, or `false` if it was definitely not.
+    pub(crate) fn insert(&mut self, item: &str) -> bool {
+        let mut hasher = Fnv1a::new();
+        hasher.write(item.as_bytes());
+        let hash = hasher.finish();
+        // Double hashing: derive every index from two halves of one hash.
+        let (h1, h2) = (hash & 0xffff_ffff, (hash >> 32) | 1);
+        let len = self.bits.len() as u64 * 64;
+        let mut present = true;
+        for i in 0..u64::from(self.hashes) {
+            let index = h1.wrapping_add(i.wrapping_mul(h2)) % len;
+            let (word, bit) = ((index / 64) as usize, 1u64 << (index % 64));
+            present &= self.bits[word] & bit != 0;
+            self.bits[word] |= bit;
+        }
+        present
+    }
+}
@@ -1,5 +1,9 @@
+use std::fmt;
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
+use std::str::FromStr;
 
+use petname::AdverbPolicy;
 use structopt::StructOpt;
 
 #[derive(StructOpt)]
@@ -10,38 +14,183 @@ use structopt::StructOpt;
     after_help = "Based on Dustin Kirkland's petname project <https://github.com/dustinkirkland/petname>."
 )]
 pub struct Cli {
-    /// Number of words in name
-    #[structopt(short, long, value_name = "WORDS", default_value = "2")]
-    pub words: u8,
+    /// Number of words in name, or a range like 2-4 to pick a number at
+    /// random for each name
+    #[structopt(short, long, value_name = "WORDS", default_value = "2", parse(try_from_str = parse_word_count))]
+    pub words: WordCount,
 
-    /// Separator between words
+    /// Separator between words. The escapes \t (tab), \n (newline), \r
+    /// (carriage return), and \\ (backslash) are recognised; any other
+    /// backslash is taken literally
     #[structopt(short, long, value_name = "SEP", default_value = "-")]
     pub separator: String,
 
-    /// Use small words (0), medium words (1), or large words (2)
-    #[structopt(short, long, value_name = "COM", possible_values = &["0", "1", "2"], default_value = "0", hide_possible_values = true)]
-    pub complexity: u8,
+    /// Separator for each gap between words in turn, instead of --separator;
+    /// repeat for each gap, e.g. --separators " " --separators - to put a
+    /// space after the first word and hyphens after the rest. Escapes are as
+    /// for --separator
+    #[structopt(
+        long,
+        value_name = "SEP",
+        number_of_values = 1,
+        conflicts_with = "separator"
+    )]
+    pub separators: Vec<String>,
+
+    /// How many words are adverbs: linear (n-2), at-most-one, or none
+    #[structopt(long, value_name = "POLICY", possible_values = &["linear", "at-most-one", "none"], default_value = "linear", parse(from_str = parse_adverb_policy))]
+    pub adverb_policy: AdverbPolicy,
+
+    /// Use small words (0), medium words (1), or large words (2) [default: 0,
+    /// or 1 with --compat]
+    #[structopt(short, long, value_name = "COM", possible_values = &["0", "1", "2"], hide_possible_values = true)]
+    pub complexity: Option<u8>,
+
+    /// Use the same defaults as upstream's petname; see README for details
+    #[structopt(long)]
+    pub compat: bool,
 
     /// Directory containing adjectives.txt, adverbs.txt, names.txt
     #[structopt(short, long = "dir", value_name = "DIR", conflicts_with = "complexity")]
     pub directory: Option<PathBuf>,
 
-    /// Generate multiple names; pass 0 to produce infinite names
-    /// (--count=0 is deprecated; use --stream instead)
-    #[structopt(long, value_name = "COUNT", default_value = "1")]
-    pub count: usize,
+    /// File containing a single word list to use for every part of the name
+    #[structopt(long, value_name = "FILE", conflicts_with_all = &["complexity", "directory"])]
+    pub flat: Option<PathBuf>,
 
-    /// Stream names continuously
-    #[structopt(long, conflicts_with = "count")]
+    /// Generate multiple names [default: 1]; with --stream, the most names
+    /// to generate; pass 0 to produce infinite names (--count=0 is
+    /// deprecated; use --stream instead)
+    #[structopt(long, value_name = "COUNT")]
+    pub count: Option<usize>,
+
+    /// Stream names continuously, up to --count names if given
+    #[structopt(long)]
     pub stream: bool,
 
+    /// Print each name this many times in a row; --count is the number of
+    /// different names
+    #[structopt(long, value_name = "N", default_value = "1")]
+    pub repeat: NonZeroUsize,
+
+    /// Prefix each name with its ordinal, counting from 1, e.g. "1: happy-cat"
+    #[structopt(short, long)]
+    pub number: bool,
+
+    /// Write names to this file instead of stdout
+    #[structopt(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+
     /// Do not generate the same name more than once
     #[structopt(long)]
     pub non_repeating: bool,
 
-    /// Maximum number of letters in each word; 0 for unlimited
-    #[structopt(short, long, value_name = "LETTERS", default_value = "0")]
-    pub letters: usize,
+    /// Maximum number of letters in each word [default: unlimited]; 0 for
+    /// unlimited (--letters=0 is deprecated; use --no-letter-limit instead)
+    #[structopt(short, long, value_name = "LETTERS")]
+    pub letters: Option<usize>,
+
+    /// Do not limit the number of letters in each word
+    #[structopt(long, conflicts_with = "letters")]
+    pub no_letter_limit: bool,
+
+    /// Use only adjectives of this many letters, or a range like 2-6
+    #[structopt(long, value_name = "LETTERS", parse(try_from_str = parse_letter_range))]
+    pub adj_letters: Option<LetterRange>,
+
+    /// Use only adverbs of this many letters, or a range like 2-6
+    #[structopt(long, value_name = "LETTERS", parse(try_from_str = parse_letter_range))]
+    pub adv_letters: Option<LetterRange>,
+
+    /// Use only names of this many letters, or a range like 4-10
+    #[structopt(long, value_name = "LETTERS", parse(try_from_str = parse_letter_range))]
+    pub name_letters: Option<LetterRange>,
+
+    /// Use only words beginning with one of these letters, ignoring case
+    #[structopt(long, value_name = "LETTERS")]
+    pub initials: Option<String>,
+
+    /// Fail unless there are at least this many possible names
+    #[structopt(long, value_name = "MIN", default_value = "1")]
+    pub min_cardinality: u128,
+
+    /// Generate names of about this many characters (see --tolerance)
+    #[structopt(long, value_name = "LENGTH", conflicts_with = "non-repeating")]
+    pub length: Option<usize>,
+
+    /// How far names may stray from --length
+    #[structopt(long, value_name = "TOLERANCE", default_value = "0")]
+    pub tolerance: usize,
+
+    /// How many attempts to make at generating each constrained name
+    #[structopt(long, value_name = "RETRIES", default_value = "100")]
+    pub retries: usize,
+
+    /// Exclude words containing control or zero-width characters
+    #[structopt(long)]
+    pub printable_only: bool,
+
+    /// Exclude words containing non-ASCII characters
+    #[structopt(long)]
+    pub ascii_only: bool,
+
+    /// Exclude profanity, as far as a small built-in blocklist can
+    #[cfg(feature = "blocklist")]
+    #[structopt(long)]
+    pub safe: bool,
+
+    /// Generate names from a template, e.g. "the {adjective} {name}"; each
+    /// {adjective}, {adverb}, and {name} is replaced by a random word, and
+    /// {{ and }} stand for literal braces
+    #[structopt(
+        long,
+        value_name = "TEMPLATE",
+        conflicts_with_all = &["words", "separator", "separators", "non-repeating", "length", "emoji", "key"]
+    )]
+    pub template: Option<String>,
+
+    /// Follow each name with a random animal emoji
+    #[structopt(long, conflicts_with_all = &["non-repeating", "length"])]
+    pub emoji: bool,
+
+    /// Generate the one name for this key, which is always the same for the
+    /// same key and options, e.g. to give a machine a stable name from its ID
+    #[structopt(
+        long,
+        value_name = "KEY",
+        conflicts_with_all = &["stream", "non-repeating", "length", "emoji", "alliterate", "ubuntu"]
+    )]
+    pub key: Option<String>,
+
+    /// Seed the random number generator, for reproducible names; overrides
+    /// the PETNAME_SEED environment variable
+    #[structopt(long, value_name = "SEED")]
+    pub seed: Option<u64>,
+
+    /// Fail, rather than warn, when the separator could make names ambiguous
+    #[structopt(long)]
+    pub strict: bool,
+
+    /// Print the words in the given list, after filtering, then exit
+    #[structopt(long, value_name = "CATEGORY", possible_values = &["adjectives", "adverbs", "names"])]
+    pub list_words: Option<String>,
+
+    /// Print the effective settings to stderr, then carry on
+    #[structopt(long)]
+    pub dump_config: bool,
+
+    /// Print the distribution of first letters in each word list to stderr
+    #[structopt(long)]
+    pub stats: bool,
+
+    /// Print the number of words of each length, across all word lists, to
+    /// stderr
+    #[structopt(long)]
+    pub length_histogram: bool,
+
+    /// Time generating this many names, report the rate to stderr, then exit
+    #[structopt(long, value_name = "N", hidden = true)]
+    pub benchmark: Option<usize>,
 
     /// Generate names where each word begins with the same letter
     #[structopt(short, long)]
@@ -56,3 +205,87 @@ pub struct Cli {
     #[structopt(short, long)]
     pub ubuntu: bool,
 }
+
+fn parse_adverb_policy(policy: &str) -> AdverbPolicy {
+    match policy {
+        "at-most-one" => AdverbPolicy::AtMostOne,
+        "none" => AdverbPolicy::None,
+        _ => AdverbPolicy::Linear,
+    }
+}
+
+/// The number of words in each name: fixed, or picked at random from an
+/// inclusive range for each name.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WordCount {
+    pub min: u8,
+    pub max: u8,
+}
+
+impl WordCount {
+    pub fn is_fixed(&self) -> bool {
+        self.min == self.max
+    }
+
+    /// Pick a number of words. This only draws from `rng` for a range, so a
+    /// fixed count yields the same names for a given seed as it always has.
+    pub fn pick<RNG: rand::Rng>(&self, rng: &mut RNG) -> u8 {
+        if self.is_fixed() {
+            self.min
+        } else {
+            rng.random_range(self.min..=self.max)
+        }
+    }
+}
+
+impl fmt::Display for WordCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_fixed() {
+            write!(f, "{}", self.min)
+        } else {
+            write!(f, "{}-{}", self.min, self.max)
+        }
+    }
+}
+
+fn parse_word_count(words: &str) -> Result<WordCount, String> {
+    let (min, max) = parse_range(words)?;
+    Ok(WordCount { min, max })
+}
+
+/// An inclusive range of word lengths, in letters.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LetterRange {
+    pub min: usize,
+    pub max: usize,
+}
+
+impl fmt::Display for LetterRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.min == self.max {
+            write!(f, "{}", self.min)
+        } else {
+            write!(f, "{}-{}", self.min, self.max)
+        }
+    }
+}
+
+fn parse_letter_range(letters: &str) -> Result<LetterRange, String> {
+    let (min, max) = parse_range(letters)?;
+    Ok(LetterRange { min, max })
+}
+
+// Parse a number, like "2", or an inclusive range, like "2-4".
+fn parse_range<T>(range: &str) -> Result<(T, T), String>
+where
+    T: FromStr + PartialOrd + fmt::Display,
+    T::Err: fmt::Display,
+{
+    let (min, max) = range.split_once('-').unwrap_or((range, range));
+    let parse = |n: &str| n.parse::<T>().map_err(|e| format!("{:?}: {}", n, e));
+    let (min, max) = (parse(min)?, parse(max)?);
+    if min > max {
+        return Err(format!("{} is greater than {}", min, max));
+    }
+    Ok((min, max))
+}
@@ -0,0 +1,217 @@
+//! Word lists that own their words, e.g. when read at run-time; see
+//! [`Dictionary`].
+
+#[cfg(feature = "std")]
+use alloc::vec;
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+
+#[cfg(feature = "std")]
+use crate::PetnameError;
+use crate::{AdverbPolicy, Petnames, Words};
+
+/// Word lists that own their words.
+///
+/// A [`Petnames`] borrows its words, which suits the built-in word lists and
+/// others compiled into a program. Words read at run-time must be kept
+/// somewhere for the `Petnames` to borrow: a `Dictionary` keeps them, and
+/// [`petnames`][`Dictionary::petnames`] borrows from it. Dropping the
+/// `Dictionary` frees the words.
+///
+/// # Examples
+///
+/// ```rust
+/// let dictionary = petname::Dictionary::new("happy sad".into(), "very".into(), "cat".into());
+/// let petnames = dictionary.petnames();
+/// assert_eq!(petname::Petnames::init("happy sad", "very", "cat"), petnames);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Dictionary {
+    lists: Lists,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Lists {
+    Separate {
+        adjectives: Vec<String>,
+        adverbs: Vec<String>,
+        names: Vec<String>,
+    },
+    Single(Vec<String>),
+}
+
+// Split `text` on whitespace into owned words, appending them to `words`.
+fn push_words(words: &mut Vec<String>, text: &str) {
+    words.extend(text.split_whitespace().map(ToOwned::to_owned));
+}
+
+// Split `text` on whitespace into owned words.
+fn split(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    push_words(&mut words, text);
+    words
+}
+
+// Borrow each of `words`, as a `Petnames` needs.
+fn borrow(words: &[String]) -> Words<'_> {
+    words.iter().map(String::as_str).collect()
+}
+
+// Read words from `reader` a line at a time, so that only one line – never
+// the whole source – is held in memory besides the words themselves.
+#[cfg(feature = "std")]
+fn read_words<R: std::io::BufRead>(mut reader: R) -> std::io::Result<Vec<String>> {
+    let (mut words, mut line) = (Vec::new(), String::new());
+    while reader.read_line(&mut line)? != 0 {
+        push_words(&mut words, &line);
+        line.clear();
+    }
+    Ok(words)
+}
+
+// Open `path` and read words from it; see `read_words`.
+#[cfg(feature = "std")]
+fn read_file(path: &std::path::Path) -> std::io::Result<Vec<String>> {
+    read_words(std::io::BufReader::new(std::fs::File::open(path)?))
+}
+
+impl Dictionary {
+    /// Constructs a new `Dictionary` from the given word lists. The words
+    /// are split on whitespace, as in [`Petnames::init`].
+    pub fn new(adjectives: String, adverbs: String, names: String) -> Self {
+        Self {
+            lists: Lists::Separate {
+                adjectives: split(&adjectives),
+                adverbs: split(&adverbs),
+                names: split(&names),
+            },
+        }
+    }
+
+    /// Constructs a new `Dictionary` from a single word list to be used for
+    /// all of the adjectives, adverbs, and names lists, as in
+    /// [`Petnames::from_single_list`].
+    pub fn from_single_list(words: String) -> Self {
+        Self {
+            lists: Lists::Single(split(&words)),
+        }
+    }
+
+    /// Constructs a new `Dictionary` by reading word lists from buffered
+    /// readers, a line at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std")]
+    /// # {
+    /// use std::io::Cursor;
+    /// let dictionary = petname::Dictionary::from_readers(
+    ///     Cursor::new("happy\nsad"),
+    ///     Cursor::new("very"),
+    ///     Cursor::new("cat dog"),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(
+    ///     petname::Petnames::init("happy sad", "very", "cat dog"),
+    ///     dictionary.petnames(),
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// This suits sources other than files, like network streams or
+    /// compressed archives. Each reader must yield valid UTF-8, and contain
+    /// words separated by whitespace. Only one line of each is held in memory
+    /// at a time, besides the words read so far.
+    ///
+    /// Requires the `std` feature.
+    ///
+    #[cfg(feature = "std")]
+    pub fn from_readers<R: std::io::BufRead>(
+        adjectives: R,
+        adverbs: R,
+        names: R,
+    ) -> std::io::Result<Self> {
+        Ok(Self {
+            lists: Lists::Separate {
+                adjectives: read_words(adjectives)?,
+                adverbs: read_words(adverbs)?,
+                names: read_words(names)?,
+            },
+        })
+    }
+
+    /// Constructs a new `Dictionary` from the files `adjectives.txt`,
+    /// `adverbs.txt`, and `names.txt` in the given directory.
+    ///
+    /// Each file should be valid UTF-8, and contain words separated by
+    /// whitespace. Every file is attempted, and all of the failures are
+    /// reported together in [`PetnameError::FileIo`].
+    ///
+    /// Requires the `std` feature.
+    ///
+    #[cfg(feature = "std")]
+    pub fn load_dir<P: AsRef<std::path::Path>>(dirname: P) -> Result<Self, PetnameError> {
+        let dirname = dirname.as_ref();
+        let mut errors = Vec::new();
+        let mut read = |filename| {
+            let path = dirname.join(filename);
+            read_file(&path)
+                .map_err(|error| errors.push((path, error.kind())))
+                .ok()
+        };
+        match (
+            read("adjectives.txt"),
+            read("adverbs.txt"),
+            read("names.txt"),
+        ) {
+            (Some(adjectives), Some(adverbs), Some(names)) => Ok(Self {
+                lists: Lists::Separate {
+                    adjectives,
+                    adverbs,
+                    names,
+                },
+            }),
+            _ => Err(PetnameError::FileIo(errors)),
+        }
+    }
+
+    /// Constructs a new `Dictionary` from a single file of words to be used
+    /// for all of the adjectives, adverbs, and names lists; see
+    /// [`from_single_list`][`Dictionary::from_single_list`].
+    ///
+    /// The file should be valid UTF-8, and contain words separated by
+    /// whitespace.
+    ///
+    /// Requires the `std` feature.
+    ///
+    #[cfg(feature = "std")]
+    pub fn load_file<P: AsRef<std::path::Path>>(filename: P) -> Result<Self, PetnameError> {
+        let filename = filename.as_ref();
+        read_file(filename)
+            .map(|words| Self {
+                lists: Lists::Single(words),
+            })
+            .map_err(|error| PetnameError::FileIo(vec![(filename.to_path_buf(), error.kind())]))
+    }
+
+    /// A [`Petnames`] borrowing the words in this `Dictionary`.
+    pub fn petnames(&self) -> Petnames<'_> {
+        let (adjectives, adverbs, names) = match self.lists {
+            Lists::Separate {
+                ref adjectives,
+                ref adverbs,
+                ref names,
+            } => (borrow(adjectives), borrow(adverbs), borrow(names)),
+            Lists::Single(ref words) => {
+                let words = borrow(words);
+                (words.clone(), words.clone(), words)
+            }
+        };
+        Petnames {
+            adjectives,
+            adverbs,
+            names,
+            adverb_policy: AdverbPolicy::default(),
+        }
+    }
+}
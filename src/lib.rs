@@ -10,7 +10,7 @@
 //!
 //! ```rust
 //! # #[cfg(feature = "std_rng")]
-//! let mut rng = rand::thread_rng();
+//! let mut rng = rand::rng();
 //! # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
 //! let pname = petname::Petnames::default().generate(&mut rng, 7, ":");
 //! ```
@@ -34,7 +34,7 @@
 //!
 //! ```rust
 //! # #[cfg(feature = "std_rng")]
-//! let mut rng = rand::thread_rng();
+//! let mut rng = rand::rng();
 //! # #[cfg(feature = "default_dictionary")]
 //! let petnames = petname::Petnames::default();
 //! # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
@@ -56,14 +56,27 @@
 //!
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod binary;
+#[cfg(feature = "bloom")]
+mod bloom;
+mod dictionary;
 
 use alloc::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
     string::{String, ToString},
     vec::Vec,
 };
 
-use itertools::Itertools;
-use rand::seq::SliceRandom;
+pub use binary::BinaryError;
+pub use dictionary::Dictionary;
+
+use core::hash::{Hash, Hasher};
+
+use rand::seq::{IndexedRandom, IteratorRandom, SliceRandom};
 
 /// Convenience function to generate a new petname from default word lists.
 #[allow(dead_code)]
@@ -73,9 +86,234 @@ pub fn petname(words: u8, separator: &str) -> String {
     Petnames::new().generate_one(words, separator)
 }
 
+/// Convenience function to generate a new petname from default word lists
+/// using the given source of randomness.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+/// let mut rng = rand::rng();
+/// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+/// let pname = petname::petname_with(&mut rng, 7, ":");
+/// ```
+///
+#[cfg(feature = "default_dictionary")]
+pub fn petname_with<RNG>(rng: &mut RNG, words: u8, separator: &str) -> String
+where
+    RNG: rand::Rng,
+{
+    Petnames::new().generate(rng, words, separator)
+}
+
+/// Generate one name with each of the small, medium, and large word lists,
+/// in that order.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+/// let mut rng = rand::rng();
+/// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+/// for (complexity, name) in petname::iter_all_complexities(&mut rng, 3, "-") {
+///     println!("{:?}: {}", complexity, name);
+/// }
+/// ```
+///
+/// This is a convenience for showing off the range of the dictionaries.
+///
+#[cfg(feature = "default_dictionary")]
+pub fn iter_all_complexities<'a, RNG>(
+    rng: &'a mut RNG,
+    words: u8,
+    separator: &'a str,
+) -> impl Iterator<Item = (Complexity, String)> + 'a
+where
+    RNG: rand::Rng,
+{
+    Complexity::ALL.iter().map(move |&complexity| {
+        let name = Petnames::with_complexity(complexity).generate(rng, words, separator);
+        (complexity, name)
+    })
+}
+
+/// Does `name` end with the correct check character?
+///
+/// # Examples
+///
+/// ```rust
+/// assert!(petname::verify_checksum("happy-cat-m"));
+/// assert!(!petname::verify_checksum("hapy-cat-m"));
+/// assert!(!petname::verify_checksum("happy-cot-m"));
+/// ```
+///
+/// See [`Petnames::generate_with_checksum`] for the algorithm.
+pub fn verify_checksum(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next_back() {
+        Some(check) => check == checksum(chars.as_str()),
+        None => false,
+    }
+}
+
+/// The check character for `name`: the sum of its code points modulo 26, as
+/// a letter from "a" to "z".
+fn checksum(name: &str) -> char {
+    let sum = name
+        .chars()
+        .fold(0u32, |sum, c| (sum + u32::from(c) % 26) % 26);
+    char::from(b'a' + sum as u8)
+}
+
+/// Is `ident` usable as a Rust identifier?
+///
+/// # Examples
+///
+/// ```rust
+/// assert!(petname::is_valid_ident("happy_cat"));
+/// assert!(!petname::is_valid_ident("happy-cat"));
+/// assert!(!petname::is_valid_ident("1st_cat"));
+/// assert!(!petname::is_valid_ident("match"));
+/// ```
+///
+/// This is deliberately stricter than Rust itself: only ASCII letters,
+/// digits, and underscores are accepted. Keywords – including those reserved
+/// for future use – and a lone underscore are rejected.
+pub fn is_valid_ident(ident: &str) -> bool {
+    let mut chars = ident.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+                && ident != "_"
+                && !RUST_KEYWORDS.contains(&ident)
+        }
+        _ => false,
+    }
+}
+
+/// Estimate the number of syllables in `word`.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(1, petname::estimate_syllables("cat"));
+/// assert_eq!(2, petname::estimate_syllables("happy"));
+/// assert_eq!(1, petname::estimate_syllables("hare"));
+/// assert_eq!(4, petname::estimate_syllables("ridiculous"));
+/// ```
+///
+/// This is a rough, English-biased heuristic: it counts groups of adjacent
+/// vowels – "a", "e", "i", "o", "u", and "y", ignoring case – not counting a
+/// final "e" that is probably silent, as in "hare" but not "eagle". Words in
+/// other languages, and plenty in English, will be miscounted. Every word
+/// with at least one letter has at least one syllable.
+pub fn estimate_syllables(word: &str) -> usize {
+    let is_vowel = |c: char| matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u' | 'y');
+    let (mut syllables, mut previous) = (0usize, None);
+    for c in word.chars() {
+        if is_vowel(c) && !previous.is_some_and(is_vowel) {
+            syllables += 1;
+        }
+        previous = Some(c);
+    }
+    let lower = word.to_lowercase();
+    if syllables > 1 && lower.ends_with('e') && !lower.ends_with("le") {
+        let before = lower.chars().rev().nth(1);
+        if !before.is_some_and(is_vowel) {
+            syllables -= 1;
+        }
+    }
+    if syllables == 0 && word.chars().any(char::is_alphabetic) {
+        1
+    } else {
+        syllables
+    }
+}
+
+/// Strict and reserved keywords, in all editions.
+const RUST_KEYWORDS: &[&str] = &[
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
 /// A word list.
 pub type Words<'a> = Vec<&'a str>;
 
+/// Decides what goes between the words of a petname.
+///
+/// A `&str` or `String` uses the same separator between every word, but this
+/// can be implemented to, for example, alternate between separators:
+///
+/// ```rust
+/// struct Alternate;
+///
+/// impl petname::SeparatorStrategy for Alternate {
+///     fn separator(&self, gap_index: usize) -> &str {
+///         ["-", "_"][gap_index % 2]
+///     }
+/// }
+///
+/// let petnames = petname::Petnames::init("b", "a", "c");
+/// # #[cfg(feature = "std_rng")]
+/// assert_eq!("a-a_b-c", petnames.generate_one(4, Alternate));
+/// ```
+pub trait SeparatorStrategy {
+    /// The separator to place in the gap after the word at `gap_index`, i.e.
+    /// the first gap – between the first and second words – is 0.
+    fn separator(&self, gap_index: usize) -> &str;
+}
+
+impl SeparatorStrategy for str {
+    fn separator(&self, _gap_index: usize) -> &str {
+        self
+    }
+}
+
+impl SeparatorStrategy for String {
+    fn separator(&self, _gap_index: usize) -> &str {
+        self
+    }
+}
+
+/// A separator for each gap, in order. When there are more gaps than
+/// separators the last separator is repeated; when there are no separators
+/// at all, words are not separated.
+///
+/// ```rust
+/// let petnames = petname::Petnames::init("b", "a", "c");
+/// # #[cfg(feature = "std_rng")]
+/// assert_eq!("a b-c", petnames.generate_one(3, &[" ", "-"][..]));
+/// # #[cfg(feature = "std_rng")]
+/// assert_eq!("a a b-c", petnames.generate_one(4, &[" ", " ", "-"][..]));
+/// ```
+impl<S> SeparatorStrategy for [S]
+where
+    S: AsRef<str>,
+{
+    fn separator(&self, gap_index: usize) -> &str {
+        self.get(gap_index)
+            .or_else(|| self.last())
+            .map_or("", AsRef::as_ref)
+    }
+}
+
+impl<T> SeparatorStrategy for &T
+where
+    T: SeparatorStrategy + ?Sized,
+{
+    fn separator(&self, gap_index: usize) -> &str {
+        (**self).separator(gap_index)
+    }
+}
+
+/// How many attempts constrained generation makes before giving up, where
+/// there is no `retries` argument – as there is to, for example,
+/// [`Petnames::generate_near_length`]. It is a sensible value for those too.
+pub const DEFAULT_RETRIES: usize = 100;
+
 /// Word lists and the logic to combine them into _petnames_.
 ///
 /// A _petname_ with `n` words will contain, in order:
@@ -84,11 +322,39 @@ pub type Words<'a> = Vec<&'a str>;
 ///   * 1 adjective when `n >= 2`, otherwise 0 adjectives.
 ///   * 1 name / noun when `n >= 1`, otherwise 0 names.
 ///
-#[derive(Clone, Debug, PartialEq)]
+/// This is the composition with the default [`AdverbPolicy::Linear`]; other
+/// policies trade adverbs for adjectives.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Petnames<'a> {
     pub adjectives: Words<'a>,
     pub adverbs: Words<'a>,
     pub names: Words<'a>,
+    pub adverb_policy: AdverbPolicy,
+}
+
+/// How many of the words between the first and the name / noun are adverbs.
+///
+/// Every petname of `n >= 2` words ends with 1 name / noun, and has at least 1
+/// adjective before it. The remaining `n - 2` words are shared between
+/// adverbs and adjectives according to this policy. For example, with
+/// `n = 4`:
+///
+/// | Policy      | Composition                           |
+/// |-------------|---------------------------------------|
+/// | `Linear`    | adverb, adverb, adjective, name       |
+/// | `AtMostOne` | adverb, adjective, adjective, name    |
+/// | `None`      | adjective, adjective, adjective, name |
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum AdverbPolicy {
+    /// `n - 2` adverbs and 1 adjective. This is the default.
+    #[default]
+    Linear,
+    /// 1 adverb – when `n >= 3` – and the rest adjectives.
+    AtMostOne,
+    /// No adverbs; `n - 1` adjectives.
+    None,
 }
 
 impl<'a> Petnames<'a> {
@@ -128,17 +394,222 @@ impl<'a> Petnames<'a> {
         )
     }
 
+    /// Constructs a new `Petnames` from the word lists of the given
+    /// complexity.
+    #[cfg(feature = "default_dictionary")]
+    pub fn with_complexity(complexity: Complexity) -> Self {
+        match complexity {
+            Complexity::Small => Self::small(),
+            Complexity::Medium => Self::medium(),
+            Complexity::Large => Self::large(),
+        }
+    }
+
+    /// Calculate the cardinality of the built-in word lists of the given
+    /// complexity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "default_dictionary")]
+    /// # {
+    /// use petname::{Complexity, Petnames};
+    /// // The smallest dictionary with at least 2³² names of 3 words.
+    /// let complexity = Complexity::all()
+    ///     .find(|&complexity| Petnames::cardinality_for(complexity, 3) >= 1 << 32);
+    /// # }
+    /// ```
+    ///
+    /// This is the same as `with_complexity(complexity).cardinality(words)`,
+    /// so it lets the dictionaries' sizes be compared, e.g. to pick the
+    /// smallest that gives enough names.
+    ///
+    #[cfg(feature = "default_dictionary")]
+    pub fn cardinality_for(complexity: Complexity, words: u8) -> u128 {
+        Self::with_complexity(complexity).cardinality(words)
+    }
+
+    /// A shared `Petnames` with the large word lists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let petnames = petname::Petnames::large_shared();
+    /// assert!(std::ptr::eq(petnames, petname::Petnames::large_shared()));
+    /// # #[cfg(feature = "std_rng")]
+    /// petnames.generate_one(3, "-");
+    /// ```
+    ///
+    /// This is constructed on first use and lives for the rest of the program,
+    /// so the large word lists are split only once. Initialisation is
+    /// thread-safe – concurrent first calls construct it exactly once – and
+    /// `Petnames` is `Sync`, so it can be used from any thread. Being shared,
+    /// it cannot be modified; clone it for that.
+    ///
+    /// Requires the `std` feature.
+    #[cfg(all(feature = "std", feature = "default_dictionary"))]
+    pub fn large_shared() -> &'static Petnames<'static> {
+        static LARGE: std::sync::OnceLock<Petnames<'static>> = std::sync::OnceLock::new();
+        LARGE.get_or_init(Petnames::large)
+    }
+
     /// Constructs a new `Petnames` from the given word lists.
     ///
     /// The words are extracted from the given strings by splitting on whitespace.
     pub fn init(adjectives: &'a str, adverbs: &'a str, names: &'a str) -> Self {
-        Self {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("init").entered();
+        let petnames = Self {
             adjectives: adjectives.split_whitespace().collect(),
             adverbs: adverbs.split_whitespace().collect(),
             names: names.split_whitespace().collect(),
+            adverb_policy: AdverbPolicy::default(),
+        };
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            adjectives = petnames.adjectives.len(),
+            adverbs = petnames.adverbs.len(),
+            names = petnames.names.len(),
+            cardinality = petnames.cardinality(2),
+            "loaded word lists"
+        );
+        petnames
+    }
+
+    /// Constructs a new `Petnames` from the given word lists, failing if any
+    /// of them is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use petname::{PetnameError, Petnames, WordKind};
+    /// assert!(Petnames::try_init("happy", "very", "cat").is_ok());
+    /// assert_eq!(
+    ///     Err(PetnameError::EmptyList(WordKind::Adverb)),
+    ///     Petnames::try_init("happy", " ", "cat"),
+    /// );
+    /// ```
+    ///
+    /// This is like [`init`][`Petnames::init`] but catches a missing word
+    /// list at construction, rather than later as an unexpectedly low
+    /// cardinality. The lists are checked in the order adjectives, adverbs,
+    /// names, and the first empty one is reported.
+    ///
+    pub fn try_init(
+        adjectives: &'a str,
+        adverbs: &'a str,
+        names: &'a str,
+    ) -> Result<Self, PetnameError> {
+        let petnames = Self::init(adjectives, adverbs, names);
+        for (kind, list) in [
+            (WordKind::Adjective, &petnames.adjectives),
+            (WordKind::Adverb, &petnames.adverbs),
+            (WordKind::Name, &petnames.names),
+        ] {
+            if list.is_empty() {
+                return Err(PetnameError::EmptyList(kind));
+            }
+        }
+        Ok(petnames)
+    }
+
+    /// Constructs a new `Petnames` from a single word list.
+    ///
+    /// The words are extracted from the given string by splitting on
+    /// whitespace, and then used for _all_ of the adjectives, adverbs, and
+    /// names lists. This trades grammatical correctness for convenience: any
+    /// word may appear in any position, so names will likely read as nonsense.
+    pub fn from_single_list(words: &'a str) -> Self {
+        let words: Words<'a> = words.split_whitespace().collect();
+        Self {
+            adjectives: words.clone(),
+            adverbs: words.clone(),
+            names: words,
+            adverb_policy: AdverbPolicy::default(),
+        }
+    }
+
+    /// Constructs a new `Petnames` from slices of words.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let petnames = petname::Petnames::from_slices(&["happy"], &["very"], &["cat"]);
+    /// assert_eq!(petname::Petnames::init("happy", "very", "cat"), petnames);
+    /// ```
+    ///
+    /// This suits word lists that are already split, e.g. static slices from a
+    /// build script. The words are used as given – they are not split on
+    /// whitespace – and only the references are copied, not the words.
+    ///
+    pub fn from_slices(adjectives: &[&'a str], adverbs: &[&'a str], names: &[&'a str]) -> Self {
+        Self {
+            adjectives: adjectives.to_vec(),
+            adverbs: adverbs.to_vec(),
+            names: names.to_vec(),
+            adverb_policy: AdverbPolicy::default(),
         }
     }
 
+    /// Use the given policy to decide how many adverbs go into a petname.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use petname::{AdverbPolicy, Petnames};
+    /// let petnames = Petnames::init("b", "a", "c").with_adverb_policy(AdverbPolicy::AtMostOne);
+    /// # #[cfg(feature = "std_rng")]
+    /// assert_eq!("a-b-b-c", petnames.generate_one(4, "-"));
+    /// ```
+    ///
+    pub fn with_adverb_policy(mut self, adverb_policy: AdverbPolicy) -> Self {
+        self.adverb_policy = adverb_policy;
+        self
+    }
+
+    /// Empty all of the word lists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut petnames = petname::Petnames::init("happy", "very", "cat");
+    /// petnames.clear();
+    /// assert_eq!(0, petnames.cardinality(1));
+    /// ```
+    ///
+    /// The adverb policy is left alone. Use the setters, like
+    /// [`set_names`][`Petnames::set_names`], to fill the lists again.
+    ///
+    pub fn clear(&mut self) {
+        self.adjectives.clear();
+        self.adverbs.clear();
+        self.names.clear();
+    }
+
+    /// Replace the adjectives, leaving the other word lists alone.
+    pub fn set_adjectives(&mut self, adjectives: Words<'a>) {
+        self.adjectives = adjectives;
+    }
+
+    /// Replace the adverbs, leaving the other word lists alone.
+    pub fn set_adverbs(&mut self, adverbs: Words<'a>) {
+        self.adverbs = adverbs;
+    }
+
+    /// Replace the names / nouns, leaving the other word lists alone.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut petnames = petname::Petnames::init("happy", "very", "cat");
+    /// petnames.set_names(vec!["dog"]);
+    /// assert_eq!(petname::Petnames::init("happy", "very", "dog"), petnames);
+    /// ```
+    ///
+    pub fn set_names(&mut self, names: Words<'a>) {
+        self.names = names;
+    }
+
     /// Keep words matching a predicate.
     ///
     /// # Examples
@@ -165,86 +636,2348 @@ impl<'a> Petnames<'a> {
         self.names.retain(|word| predicate(word));
     }
 
-    /// Calculate the cardinality of this `Petnames`.
+    /// Keep words for which `predicate` returns `true`, given each word and
+    /// the kind of list it is in.
     ///
-    /// If this is low, names may be repeated by the generator with a higher
-    /// frequency than your use-case may allow. If it is 0 (zero) the generator
-    /// will panic (unless `words` is also zero).
+    /// # Examples
     ///
-    /// This can saturate. If the total possible combinations of words exceeds
-    /// `u128::MAX` then this will return `u128::MAX`.
-    pub fn cardinality(&self, words: u8) -> u128 {
-        Lists::new(self, words)
-            .map(|list| list.len() as u128)
-            .reduce(u128::saturating_mul)
-            .unwrap_or(0u128)
+    /// ```rust
+    /// use petname::{Petnames, WordKind};
+    /// let mut petnames = Petnames::init("big bigger", "very", "cat cats");
+    /// petnames.retain_with_metadata(|kind, word| kind != WordKind::Name || word.len() <= 3);
+    /// assert_eq!(Petnames::init("big bigger", "very", "cat"), petnames);
+    /// ```
+    ///
+    /// Words are visited in order: adjectives, adverbs, then names.
+    ///
+    pub fn retain_with_metadata<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(WordKind, &str) -> bool,
+    {
+        self.adjectives
+            .retain(|word| predicate(WordKind::Adjective, word));
+        self.adverbs
+            .retain(|word| predicate(WordKind::Adverb, word));
+        self.names.retain(|word| predicate(WordKind::Name, word));
     }
 
-    /// Generate a new petname.
+    /// Keep only adjectives of between `min` and `max` characters, inclusive.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
-    /// let mut rng = rand::thread_rng();
-    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
-    /// petname::Petnames::default().generate(&mut rng, 7, ":");
+    /// let mut petnames = petname::Petnames::init("big bigger", "very", "cat cats");
+    /// petnames.retain_adjectives_len(2, 3);
+    /// assert_eq!(petname::Petnames::init("big", "very", "cat cats"), petnames);
     /// ```
     ///
-    /// # Notes
+    /// Other lists are left alone, so each part of a name can have its own
+    /// limits. Lengths are counted in characters, not bytes.
     ///
-    /// This may return fewer words than you request if one or more of the word
-    /// lists are empty. For example, if there are no adverbs, requesting 3 or
-    /// more words may still yield only "doubtful-salmon".
+    pub fn retain_adjectives_len(&mut self, min: usize, max: usize) {
+        retain_len(&mut self.adjectives, min, max);
+    }
+
+    /// Keep only adverbs of between `min` and `max` characters, inclusive;
+    /// see [`retain_adjectives_len`][`Petnames::retain_adjectives_len`].
+    pub fn retain_adverbs_len(&mut self, min: usize, max: usize) {
+        retain_len(&mut self.adverbs, min, max);
+    }
+
+    /// Keep only names of between `min` and `max` characters, inclusive; see
+    /// [`retain_adjectives_len`][`Petnames::retain_adjectives_len`].
+    pub fn retain_names_len(&mut self, min: usize, max: usize) {
+        retain_len(&mut self.names, min, max);
+    }
+
+    /// Keep at most one word from each group of confusable words.
+    ///
+    /// # Examples
     ///
-    pub fn generate<RNG>(&self, rng: &mut RNG, words: u8, separator: &str) -> String
+    /// ```rust
+    /// let mut petnames = petname::Petnames::init("fair", "very", "bear bare hare");
+    /// petnames.retain_no_confusables(&[&["bear", "bare"], &["fair", "fare"]]);
+    /// assert_eq!(petname::Petnames::init("fair", "very", "bear hare"), petnames);
+    /// ```
+    ///
+    /// This is entirely data-driven: nothing is known about what sounds alike,
+    /// e.g. homophones, so `confusable_groups` must say. Within each list,
+    /// only the first word to appear from a group is kept. Words in different
+    /// lists are never confused with one another because they appear in
+    /// different positions in a name.
+    ///
+    pub fn retain_no_confusables(&mut self, confusable_groups: &[&[&str]]) {
+        let mut seen = BTreeSet::new();
+        self.retain_with_metadata(|kind, word| {
+            match confusable_groups
+                .iter()
+                .position(|group| group.contains(&word))
+            {
+                Some(group) => seen.insert((kind, group)),
+                None => true,
+            }
+        })
+    }
+
+    /// Take the `index`-th of `total` shards of the word lists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let petnames = petname::Petnames::init("a b c d", "e f", "g h i");
+    /// assert_eq!(petname::Petnames::init("a b", "e", "g"), petnames.shard(0, 2));
+    /// assert_eq!(petname::Petnames::init("c d", "f", "h i"), petnames.shard(1, 2));
+    /// ```
+    ///
+    /// Each word list is divided into `total` contiguous slices, as evenly as
+    /// possible, and the `index`-th slice of each is kept. Across all shards,
+    /// every word appears exactly once, so separate instances can each use
+    /// their own shard to avoid generating the same names as one another.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is not less than `total`.
+    ///
+    pub fn shard(&self, index: usize, total: usize) -> Petnames<'a> {
+        assert!(
+            index < total,
+            "shard index {} out of range 0..{}",
+            index,
+            total
+        );
+        let shard = |list: &Words<'a>| {
+            let bound = |i: usize| (list.len() as u128 * i as u128 / total as u128) as usize;
+            list[bound(index)..bound(index + 1)].to_vec()
+        };
+        Self {
+            adjectives: shard(&self.adjectives),
+            adverbs: shard(&self.adverbs),
+            names: shard(&self.names),
+            adverb_policy: self.adverb_policy,
+        }
+    }
+
+    /// Split each word list at random into two disjoint parts, e.g. for
+    /// training and validation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std_rng")]
+    /// # {
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// let petnames = petname::Petnames::init("a b c d", "e f", "g h i j k");
+    /// let (train, validate) = petnames.partition(&mut StdRng::seed_from_u64(1), 0.75);
+    /// assert_eq!((3, 1), (train.adjectives.len(), validate.adjectives.len()));
+    /// # }
+    /// ```
+    ///
+    /// In each list, the given `ratio` of words – rounded to the nearest
+    /// whole word – goes into the first `Petnames`, and the rest into the
+    /// second. A `ratio` outside of `0.0..=1.0` is clamped to it. Words are
+    /// assigned using `rng`, so a seeded `rng` gives the same partition every
+    /// time, but they are also shuffled. Both parts keep the adverb policy.
+    ///
+    pub fn partition<RNG>(&self, rng: &mut RNG, ratio: f64) -> (Petnames<'a>, Petnames<'a>)
     where
         RNG: rand::Rng,
     {
-        Itertools::intersperse(
-            Lists::new(self, words)
-                .filter_map(|list| list.choose(rng))
-                .cloned(),
-            separator,
+        let ratio = ratio.clamp(0.0, 1.0);
+        let mut partition = |list: &Words<'a>| {
+            let mut first = list.clone();
+            first.shuffle(rng);
+            let at = ((list.len() as f64 * ratio + 0.5) as usize).min(list.len());
+            let second = first.split_off(at);
+            (first, second)
+        };
+        let (adjectives, other_adjectives) = partition(&self.adjectives);
+        let (adverbs, other_adverbs) = partition(&self.adverbs);
+        let (names, other_names) = partition(&self.names);
+        (
+            Self {
+                adjectives,
+                adverbs,
+                names,
+                adverb_policy: self.adverb_policy,
+            },
+            Self {
+                adjectives: other_adjectives,
+                adverbs: other_adverbs,
+                names: other_names,
+                adverb_policy: self.adverb_policy,
+            },
         )
-        .collect::<String>()
     }
 
-    /// Generate a single new petname.
+    /// Keep only words that are entirely printable.
     ///
-    /// This is like `generate` but uses `rand::thread_rng` as the random
-    /// source. For efficiency use `generate` when creating multiple names, or
-    /// when you want to use a custom source of randomness.
-    #[cfg(feature = "std_rng")]
-    pub fn generate_one(&self, words: u8, separator: &str) -> String {
-        self.generate(&mut rand::thread_rng(), words, separator)
+    /// This removes words containing:
+    ///
+    ///   * control characters, i.e. those in the Unicode `Cc` category, as
+    ///     determined by [`char::is_control`];
+    ///   * zero-width characters from the Unicode `Cf` (format) category:
+    ///     U+200B ZERO WIDTH SPACE, U+200C ZERO WIDTH NON-JOINER, U+200D ZERO
+    ///     WIDTH JOINER, U+2060 WORD JOINER, and U+FEFF ZERO WIDTH NO-BREAK
+    ///     SPACE (a.k.a. the byte order mark).
+    ///
+    /// Such characters can produce names that are invisible or that look
+    /// identical to other names. Whitespace is not considered here because
+    /// word lists are already split on whitespace.
+    pub fn retain_printable(&mut self) {
+        self.retain(|word| !word.chars().any(is_unprintable))
     }
 
-    /// Iterator yielding petnames.
+    /// Keep only words beginning with one of the `allowed` characters.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
-    /// let mut rng = rand::thread_rng();
-    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
-    /// let petnames = petname::Petnames::default();
-    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
-    /// let mut iter = petnames.iter(&mut rng, 4, "_");
-    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
-    /// println!("name: {}", iter.next().unwrap());
+    /// let mut petnames = petname::Petnames::init("able bad", "very", "Eel cat");
+    /// petnames.retain_initials(&['a', 'e', 'i', 'o', 'u']);
+    /// assert_eq!(petname::Petnames::init("able", "", ""), petnames);
     /// ```
     ///
-    pub fn iter<RNG>(&self, rng: &'a mut RNG, words: u8, separator: &str) -> Names<RNG>
-    where
-        RNG: rand::Rng,
-    {
-        Names {
-            petnames: self,
-            rng,
-            words,
-            separator: separator.to_string(),
-        }
+    /// This is case-sensitive; see
+    /// [`retain_initials_ignore_case`][`Petnames::retain_initials_ignore_case`].
+    ///
+    pub fn retain_initials(&mut self, allowed: &[char]) {
+        self.retain(|word| word.chars().next().is_some_and(|c| allowed.contains(&c)))
+    }
+
+    /// Keep only words beginning with one of the `allowed` characters,
+    /// ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut petnames = petname::Petnames::init("able bad", "very", "Eel cat");
+    /// petnames.retain_initials_ignore_case(&['A', 'E', 'I', 'O', 'U']);
+    /// assert_eq!(petname::Petnames::init("able", "", "Eel"), petnames);
+    /// ```
+    ///
+    pub fn retain_initials_ignore_case(&mut self, allowed: &[char]) {
+        self.retain(|word| {
+            word.chars().next().is_some_and(|c| {
+                allowed
+                    .iter()
+                    .any(|a| a.to_lowercase().eq(c.to_lowercase()))
+            })
+        })
+    }
+
+    /// Keep only words beginning with the same letter, so that names
+    /// alliterate, returning that letter.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std_rng")]
+    /// # {
+    /// use petname::{PetnameError, Petnames};
+    /// let mut rng = rand::rng();
+    /// let mut petnames = Petnames::init("able bad", "very bold", "ant bat");
+    /// assert_eq!(Ok('b'), petnames.retain_alliterating(&mut rng, None));
+    /// assert_eq!(Petnames::init("bad", "bold", "bat"), petnames);
+    /// assert_eq!(
+    ///     Err(PetnameError::Alliteration(Some('c'))),
+    ///     petnames.retain_alliterating(&mut rng, Some('c')),
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// With `letter`, that letter is used; without, one is chosen at random
+    /// from those that begin a word in every list. Letters are compared
+    /// exactly, so "B" and "b" differ. This fails with
+    /// [`PetnameError::Alliteration`], leaving the word lists as they were,
+    /// if no letter begins a word in every list – including the adverbs,
+    /// whatever the adverb policy.
+    ///
+    pub fn retain_alliterating<RNG>(
+        &mut self,
+        rng: &mut RNG,
+        letter: Option<char>,
+    ) -> Result<char, PetnameError>
+    where
+        RNG: rand::Rng,
+    {
+        let initials = |list: &Words| -> BTreeSet<char> {
+            list.iter().filter_map(|word| word.chars().next()).collect()
+        };
+        let mut common = initials(&self.adjectives);
+        for list in [&self.adverbs, &self.names] {
+            let others = initials(list);
+            common.retain(|c| others.contains(c));
+        }
+        let letter = match letter {
+            Some(letter) if common.contains(&letter) => letter,
+            Some(letter) => return Err(PetnameError::Alliteration(Some(letter))),
+            None => *common
+                .iter()
+                .choose(rng)
+                .ok_or(PetnameError::Alliteration(None))?,
+        };
+        self.retain(|word| word.starts_with(letter));
+        Ok(letter)
+    }
+
+    /// Keep only the minimal words in each list, i.e. those that do not begin
+    /// with another, shorter, word from the same list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut petnames = petname::Petnames::init("", "", "cat cats category dog");
+    /// petnames.retain_minimal();
+    /// assert_eq!(vec!["cat", "dog"], petnames.names);
+    /// ```
+    ///
+    /// This is a heuristic to reduce visual redundancy, e.g. between "cat" and
+    /// "cats". It knows nothing of language, so it will also remove unrelated
+    /// words like "category", and misses related words like "mice" and
+    /// "mouse".
+    ///
+    pub fn retain_minimal(&mut self) {
+        fn minimal(list: &mut Words) {
+            let mut sorted = list.clone();
+            sorted.sort_unstable();
+            list.retain(|word| {
+                !word
+                    .char_indices()
+                    .skip(1)
+                    .any(|(index, _)| sorted.binary_search(&&word[..index]).is_ok())
+            });
+        }
+        minimal(&mut self.adjectives);
+        minimal(&mut self.adverbs);
+        minimal(&mut self.names);
+    }
+
+    /// Keep only words matching the regular expression `pattern`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "regex")]
+    /// # {
+    /// let mut petnames = petname::Petnames::init("able bad", "very", "cat nut");
+    /// petnames.retain_regex("^[a-m]").unwrap();
+    /// assert_eq!(petname::Petnames::init("able bad", "", "cat"), petnames);
+    /// # }
+    /// ```
+    ///
+    /// The pattern is compiled once and applied to the words of every list.
+    /// It is unanchored, so use `^` and `$` to match whole words. If the
+    /// pattern does not compile the words are left unchanged.
+    ///
+    /// Requires the `regex` feature.
+    #[cfg(feature = "regex")]
+    pub fn retain_regex(&mut self, pattern: &str) -> Result<(), regex::Error> {
+        let regex = regex::Regex::new(pattern)?;
+        self.retain(|word| regex.is_match(word));
+        Ok(())
+    }
+
+    /// Remove words found in a small, built-in, blocklist of profanity and
+    /// slurs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "blocklist")]
+    /// # {
+    /// let mut petnames = petname::Petnames::init("happy Shitty", "very", "cat");
+    /// petnames.retain_safe();
+    /// assert_eq!(petname::Petnames::init("happy", "very", "cat"), petnames);
+    /// # }
+    /// ```
+    ///
+    /// This is best-effort only. Words are removed if they match an entry in
+    /// the blocklist exactly, ignoring case; words that merely _contain_ an
+    /// entry are kept, to avoid removing innocent words like "class". The
+    /// blocklist is English only, and no list is complete, so check names
+    /// yourself where it matters. The built-in word lists contain none of
+    /// the blocked words; this is for custom word lists.
+    ///
+    /// Requires the `blocklist` feature.
+    #[cfg(feature = "blocklist")]
+    pub fn retain_safe(&mut self) {
+        let blocklist: Words = include_str!("../words/blocklist.txt")
+            .split_whitespace()
+            .collect();
+        // The blocklist is all lower case.
+        self.retain(|word| !blocklist.contains(&word.to_lowercase().as_str()))
+    }
+
+    /// Keep only words made up entirely of ASCII characters.
+    pub fn retain_ascii(&mut self) {
+        self.retain(|word| word.is_ascii())
+    }
+
+    /// Keep only words that are also in the corresponding list of `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut petnames = petname::Petnames::init("happy sad", "very", "cat dog");
+    /// petnames.intersect(&petname::Petnames::init("happy", "Very", "cow dog"));
+    /// assert_eq!(petname::Petnames::init("happy", "", "dog"), petnames);
+    /// ```
+    ///
+    /// Words are compared exactly, so this is case-sensitive. The order of
+    /// words in `self` is preserved.
+    pub fn intersect(&mut self, other: &Petnames) {
+        fn intersect(list: &mut Words, other: &Words) {
+            let other: BTreeSet<&str> = other.iter().copied().collect();
+            list.retain(|word| other.contains(word));
+        }
+        intersect(&mut self.adjectives, &other.adjectives);
+        intersect(&mut self.adverbs, &other.adverbs);
+        intersect(&mut self.names, &other.names);
+    }
+
+    /// Can petnames made with `separator` be split back into their words?
+    ///
+    /// This is `false` if any word contains `separator`, e.g. "well-known"
+    /// with a separator of "-", in which case the name is ambiguous. An empty
+    /// separator is never safe.
+    pub fn separator_is_safe(&self, separator: &str) -> bool {
+        [&self.adjectives, &self.adverbs, &self.names]
+            .iter()
+            .all(|list| list.iter().all(|word| !word.contains(separator)))
+    }
+
+    /// Could `name` have been generated with `words` words and `separator`?
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let petnames = petname::Petnames::init("happy", "very", "cat");
+    /// assert!(petnames.matches_shape("very-happy-cat", 3, "-"));
+    /// assert!(!petnames.matches_shape("happy-cat", 3, "-"));
+    /// assert!(!petnames.matches_shape("happy-very-cat", 3, "-"));
+    /// ```
+    ///
+    /// The name must split on `separator` into exactly the right number of
+    /// words, and each word must be in the list – adverbs, adjectives, or
+    /// names – for its position, taking the adverb policy into account. As
+    /// when generating, positions whose list is empty are skipped, and an
+    /// empty name has no words at all. If the separator is empty the name is
+    /// not split, so it can only match a single word. Words are compared
+    /// exactly.
+    ///
+    pub fn matches_shape(&self, name: &str, words: u8, separator: &str) -> bool {
+        let segments: Vec<&str> = if name.is_empty() {
+            Vec::new()
+        } else if separator.is_empty() {
+            alloc::vec![name]
+        } else {
+            name.split(separator).collect()
+        };
+        let lists: Vec<&Words> = Lists::new(self, words)
+            .filter(|list| !list.is_empty())
+            .collect();
+        segments.len() == lists.len()
+            && segments
+                .iter()
+                .zip(lists)
+                .all(|(word, list)| list.contains(word))
+    }
+
+    /// Estimate the memory, in bytes, occupied by the word lists.
+    ///
+    /// This is the byte length of every word, plus the space taken by each
+    /// list – its `Vec` and the `&str` references it holds. It is only an
+    /// estimate: allocator overhead is not counted and, because words are
+    /// borrowed, their bytes may live elsewhere – in the binary for the
+    /// built-in dictionaries – or be shared between lists, as with
+    /// [`from_single_list`][`Petnames::from_single_list`].
+    pub fn memory_footprint(&self) -> usize {
+        [&self.adjectives, &self.adverbs, &self.names]
+            .iter()
+            .map(|list| {
+                core::mem::size_of::<Words<'a>>()
+                    + list.capacity() * core::mem::size_of::<&str>()
+                    + list.iter().map(|word| word.len()).sum::<usize>()
+            })
+            .sum()
+    }
+
+    /// Count the words of each length, in characters, across all of the word
+    /// lists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let petnames = petname::Petnames::init("happy sad", "very", "cat dog");
+    /// let histogram = petnames.length_histogram();
+    /// assert_eq!(vec![(3, 3), (4, 1), (5, 1)], histogram.into_iter().collect::<Vec<_>>());
+    /// ```
+    ///
+    /// This helps when tuning a dictionary, e.g. to see how much a
+    /// [`retain`][`Petnames::retain`] on length would remove. A word in more
+    /// than one list is counted once for each.
+    ///
+    pub fn length_histogram(&self) -> BTreeMap<usize, usize> {
+        let mut histogram = BTreeMap::new();
+        for list in &[&self.adjectives, &self.adverbs, &self.names] {
+            for word in list.iter() {
+                *histogram.entry(word.chars().count()).or_insert(0) += 1;
+            }
+        }
+        histogram
+    }
+
+    /// Generate `n` petnames and report on their lengths and how many are
+    /// unique.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let petnames = petname::Petnames::init("happy sad", "very", "cat dog");
+    /// # #[cfg(feature = "std_rng")]
+    /// let stats = petnames.sample_stats(&mut rand::rng(), 2, "-", 100);
+    /// # #[cfg(feature = "std_rng")]
+    /// assert!(stats.min_len <= stats.max_len && stats.unique <= 4);
+    /// ```
+    ///
+    /// This gives quick, empirical feedback when tuning a dictionary and its
+    /// filters, complementing [`cardinality`][`Petnames::cardinality`] and
+    /// [`length_histogram`][`Petnames::length_histogram`]. Lengths are in
+    /// characters, not bytes. Every name is kept to count the unique ones, so
+    /// memory use grows with `n`.
+    ///
+    pub fn sample_stats<RNG, SEP>(
+        &self,
+        rng: &mut RNG,
+        words: u8,
+        separator: SEP,
+        n: usize,
+    ) -> SampleStats
+    where
+        RNG: rand::Rng,
+        SEP: SeparatorStrategy,
+    {
+        let mut names = BTreeSet::new();
+        let (mut min_len, mut max_len, mut total_len) = (usize::MAX, 0, 0u128);
+        for _ in 0..n {
+            let name = self.generate(rng, words, &separator);
+            let len = name.chars().count();
+            min_len = min_len.min(len);
+            max_len = max_len.max(len);
+            total_len += len as u128;
+            names.insert(name);
+        }
+        SampleStats {
+            samples: n,
+            min_len: if n == 0 { 0 } else { min_len },
+            max_len,
+            mean_len: if n == 0 {
+                0.0
+            } else {
+                total_len as f64 / n as f64
+            },
+            unique: names.len(),
+        }
+    }
+
+    /// Calculate the cardinality of this `Petnames`.
+    ///
+    /// If this is low, names may be repeated by the generator with a higher
+    /// frequency than your use-case may allow. If it is 0 (zero) then one or
+    /// more of the word lists needed is empty, and the generator will produce
+    /// names with fewer words than requested – or empty names – but it will
+    /// not panic.
+    ///
+    /// There is exactly one name of 0 (zero) words: the empty name. Hence
+    /// `cardinality(0)` is always 1.
+    ///
+    /// This can saturate. If the total possible combinations of words exceeds
+    /// `u128::MAX` then this will return `u128::MAX`.
+    pub fn cardinality(&self, words: u8) -> u128 {
+        Lists::new(self, words)
+            .map(|list| list.len() as u128)
+            .fold(1u128, u128::saturating_mul)
+    }
+
+    /// Calculate the cardinality of this `Petnames`, or `None` if it exceeds
+    /// `u128::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let petnames = petname::Petnames::init("a b", "c d e", "f g h i");
+    /// assert_eq!(Some(24), petnames.cardinality_checked(3));
+    /// ```
+    ///
+    /// This is like [`cardinality`][`Petnames::cardinality`] but tells
+    /// saturation apart from a cardinality of exactly `u128::MAX`, e.g. for
+    /// when names are used as tokens and the size of the space matters.
+    ///
+    pub fn cardinality_checked(&self, words: u8) -> Option<u128> {
+        Lists::new(self, words)
+            .map(|list| list.len() as u128)
+            .try_fold(1u128, u128::checked_mul)
+    }
+
+    /// Check that there are at least `minimum` possible names of `words`
+    /// words.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let petnames = petname::Petnames::init("happy sad", "very", "cat dog");
+    /// assert!(petnames.require_cardinality(2, 4).is_ok());
+    /// assert!(petnames.require_cardinality(2, 5).is_err());
+    /// ```
+    ///
+    /// This is a guardrail for custom word lists, which may be smaller than
+    /// expected, e.g. after filtering.
+    ///
+    pub fn require_cardinality(&self, words: u8, minimum: u128) -> Result<(), CardinalityError> {
+        let cardinality = self.cardinality(words);
+        if cardinality < minimum {
+            Err(CardinalityError {
+                words,
+                cardinality,
+                minimum,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// The length, in bytes, of the longest petname that could be generated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let petnames = petname::Petnames::init("happy sad", "very", "cat mouse");
+    /// assert_eq!("happy-mouse".len(), petnames.max_name_length(2, "-"));
+    /// ```
+    ///
+    /// This is the sum of the lengths of the longest word in each list
+    /// consulted, plus the separators between them. Empty lists contribute
+    /// neither a word nor a separator, just as in
+    /// [`generate`][`Petnames::generate`]. Use it to size buffers.
+    ///
+    pub fn max_name_length(&self, words: u8, separator: &str) -> usize {
+        max_name_length(Lists::new(self, words).map(|list| &list[..]), separator)
+    }
+
+    /// A stable fingerprint of the word lists.
+    ///
+    /// This is a 64-bit [FNV-1a][fnv] hash of the adjectives, adverbs, and
+    /// names lists, in that order. It is _order-dependent_: the same words in
+    /// a different order will, in all likelihood, produce a different
+    /// fingerprint. Unlike the derived [`Hash`] implementation used with
+    /// `std`'s `RandomState`, the fingerprint is the same across runs and
+    /// across platforms, so it can be used to key caches by dictionary.
+    ///
+    /// [fnv]: http://www.isthe.com/chongo/tech/comp/fnv/
+    pub fn fingerprint(&self) -> u64 {
+        let mut hash = Fnv1a::new();
+        for list in &[&self.adjectives, &self.adverbs, &self.names] {
+            hash.write(&(list.len() as u64).to_le_bytes());
+            for word in list.iter() {
+                hash.write(&(word.len() as u64).to_le_bytes());
+                hash.write(word.as_bytes());
+            }
+        }
+        hash.finish()
+    }
+
+    /// Generate a new petname.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// petname::Petnames::default().generate(&mut rng, 7, ":");
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// This may return fewer words than you request if one or more of the word
+    /// lists are empty. For example, if there are no adverbs, requesting 3 or
+    /// more words may still yield only "doubtful-salmon". If all of the word
+    /// lists are empty, or `words` is zero, this returns an empty string. It
+    /// never panics.
+    ///
+    /// The `separator` is usually a `&str`, but can be anything that
+    /// implements [`SeparatorStrategy`].
+    ///
+    pub fn generate<RNG, SEP>(&self, rng: &mut RNG, words: u8, separator: SEP) -> String
+    where
+        RNG: rand::Rng,
+        SEP: SeparatorStrategy,
+    {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!("generate", words).entered();
+        let mut name = String::new();
+        self.generate_into(rng, words, separator, &mut name);
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            name = name.as_str(),
+            cardinality = self.cardinality(words),
+            "generated petname"
+        );
+        name
+    }
+
+    /// Append a new petname to `name`; see [`generate`][`Petnames::generate`].
+    fn generate_into<RNG, SEP>(&self, rng: &mut RNG, words: u8, separator: SEP, name: &mut String)
+    where
+        RNG: rand::Rng,
+        SEP: SeparatorStrategy,
+    {
+        for (index, word) in Lists::new(self, words)
+            .filter_map(|list| list.choose(rng))
+            .enumerate()
+        {
+            if index > 0 {
+                name.push_str(separator.separator(index - 1));
+            }
+            name.push_str(word);
+        }
+    }
+
+    /// Generate a new petname in a reusable, thread-local, buffer, and pass
+    /// it to `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let petnames = petname::Petnames::default();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut total = 0;
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// for _ in 0..1000 {
+    ///     total += petnames.generate_buffered(&mut rng, 2, "-", |name| name.len());
+    /// }
+    /// ```
+    ///
+    /// This is for generating very many names, one at a time, on one thread,
+    /// when allocating a new `String` for each would dominate. The buffer
+    /// grows to fit the longest name so far and is then reused, so after a
+    /// warm-up there are no allocations at all.
+    ///
+    /// The name is only borrowed by `f`, and only for the duration of the
+    /// call: it cannot be kept, because the buffer is refilled by the next
+    /// call. Return `name.to_string()` from `f` to keep a copy – though
+    /// [`generate`][`Petnames::generate`] does that more directly. Calling
+    /// this again from within `f` is allowed, but the inner call will use a
+    /// new buffer.
+    ///
+    /// Requires the `std` feature.
+    ///
+    #[cfg(feature = "std")]
+    pub fn generate_buffered<RNG, SEP, F, R>(
+        &self,
+        rng: &mut RNG,
+        words: u8,
+        separator: SEP,
+        f: F,
+    ) -> R
+    where
+        RNG: rand::Rng,
+        SEP: SeparatorStrategy,
+        F: FnOnce(&str) -> R,
+    {
+        std::thread_local! {
+            static BUFFER: core::cell::Cell<String> = const { core::cell::Cell::new(String::new()) };
+        }
+        BUFFER.with(|buffer| {
+            // Take the buffer so that a nested call finds it empty, rather
+            // than panicking as it would with a `RefCell`.
+            let mut name = buffer.take();
+            name.clear();
+            self.generate_into(rng, words, separator, &mut name);
+            let result = f(&name);
+            buffer.set(name);
+            result
+        })
+    }
+
+    /// Generate a new petname of an adjective and a name / noun, e.g.
+    /// "happy-cat".
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// petname::Petnames::default().adjective_noun(&mut rng, "-");
+    /// ```
+    ///
+    /// This is the same as `generate(rng, 2, separator)`: two-word names are
+    /// always an adjective then a name, whatever the adverb policy.
+    ///
+    pub fn adjective_noun<RNG>(&self, rng: &mut RNG, separator: &str) -> String
+    where
+        RNG: rand::Rng,
+    {
+        self.generate(rng, 2, separator)
+    }
+
+    /// Generate a new petname of a single name / noun, e.g. "cat".
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// petname::Petnames::default().noun_only(&mut rng);
+    /// ```
+    ///
+    /// This is the same as `generate(rng, 1, "")`.
+    ///
+    pub fn noun_only<RNG>(&self, rng: &mut RNG) -> String
+    where
+        RNG: rand::Rng,
+    {
+        self.generate(rng, 1, "")
+    }
+
+    /// Generate a new petname by filling in a template, e.g. "the {adjective}
+    /// {name} who runs {adverb}".
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// petname::Petnames::default().generate_template(&mut rng, "the {adjective} {name}");
+    /// ```
+    ///
+    /// Each `{adjective}`, `{adverb}`, and `{name}` placeholder is replaced by
+    /// a word chosen from the corresponding list, or by nothing if that list is
+    /// empty. Every placeholder gets its own word, so "{name} and {name}" may
+    /// name two different things. Everything else is copied as-is, including
+    /// unknown placeholders like "{colour}"; see
+    /// [`check_template`][`Petnames::check_template`] to reject those. Write
+    /// `{{` and `}}` for literal braces.
+    ///
+    pub fn generate_template<RNG>(&self, rng: &mut RNG, template: &str) -> String
+    where
+        RNG: rand::Rng,
+    {
+        let mut name = String::with_capacity(template.len());
+        for part in template_parts(template) {
+            match part {
+                TemplatePart::Text(text) => name.push_str(text),
+                TemplatePart::Placeholder(placeholder) => {
+                    match WordKind::from_placeholder(placeholder) {
+                        Some(kind) => name
+                            .push_str(self.list_for(kind).choose(rng).copied().unwrap_or_default()),
+                        None => {
+                            name.push('{');
+                            name.push_str(placeholder);
+                            name.push('}');
+                        }
+                    }
+                }
+            }
+        }
+        name
+    }
+
+    /// Check that every placeholder in `template` is one that
+    /// [`generate_template`][`Petnames::generate_template`] knows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use petname::Petnames;
+    /// assert!(Petnames::check_template("the {adjective} {name}").is_ok());
+    /// assert!(Petnames::check_template("the {{colour}} {name}").is_ok());
+    /// assert!(Petnames::check_template("the {colour} {name}").is_err());
+    /// ```
+    ///
+    pub fn check_template(template: &str) -> Result<(), UnknownPlaceholder> {
+        let unknown = template_parts(template).find_map(|part| match part {
+            TemplatePart::Placeholder(placeholder)
+                if WordKind::from_placeholder(placeholder).is_none() =>
+            {
+                Some(placeholder)
+            }
+            _ => None,
+        });
+        match unknown {
+            Some(placeholder) => Err(UnknownPlaceholder(placeholder.to_string())),
+            None => Ok(()),
+        }
+    }
+
+    /// The word list for words of the given kind.
+    fn list_for(&self, kind: WordKind) -> &Words<'a> {
+        match kind {
+            WordKind::Adverb => &self.adverbs,
+            WordKind::Adjective => &self.adjectives,
+            WordKind::Name => &self.names,
+        }
+    }
+
+    /// Generate a new petname using a dynamically dispatched RNG.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng: Box<dyn rand::RngCore> = Box::new(rand::rng());
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// petname::Petnames::default().generate_dyn(&mut *rng, 7, ":");
+    /// ```
+    ///
+    /// This is like `generate` but is not generic, so it can be used with an
+    /// RNG behind a trait object.
+    ///
+    pub fn generate_dyn(
+        &self,
+        mut rng: &mut dyn rand::RngCore,
+        words: u8,
+        separator: &str,
+    ) -> String {
+        self.generate(&mut rng, words, separator)
+    }
+
+    /// Generate the words of a new petname, each tagged with its kind.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use petname::WordKind;
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let petnames = petname::Petnames::default();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let words = petnames.generate_tagged(&mut rng, 3);
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// assert_eq!(Some(WordKind::Name), words.last().map(|(kind, _)| *kind));
+    /// ```
+    ///
+    /// The words are in the same order as they would appear in a name from
+    /// [`generate`][`Petnames::generate`], and are subject to the same caveat:
+    /// there will be fewer words than requested if a word list is empty.
+    ///
+    pub fn generate_tagged<RNG>(&self, rng: &mut RNG, words: u8) -> Vec<(WordKind, &str)>
+    where
+        RNG: rand::Rng,
+    {
+        Lists::new(self, words)
+            .tagged()
+            .filter_map(|(kind, list)| list.choose(rng).map(|word| (kind, *word)))
+            .collect()
+    }
+
+    /// Generate a new petname with its words in reverse order, name / noun
+    /// first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// petname::Petnames::default().generate_reversed(&mut rng, 3, "-");
+    /// ```
+    ///
+    /// The words are chosen exactly as in [`generate`][`Petnames::generate`]
+    /// – given the same `rng` state, the same words are chosen – but are
+    /// joined last to first, e.g. "cat-little-happy". Separators are still
+    /// applied from the left, so the first gap gets the first separator.
+    ///
+    pub fn generate_reversed<RNG, SEP>(&self, rng: &mut RNG, words: u8, separator: SEP) -> String
+    where
+        RNG: rand::Rng,
+        SEP: SeparatorStrategy,
+    {
+        let mut name = String::new();
+        for (index, (_, word)) in self
+            .generate_tagged(rng, words)
+            .into_iter()
+            .rev()
+            .enumerate()
+        {
+            if index > 0 {
+                name.push_str(separator.separator(index - 1));
+            }
+            name.push_str(word);
+        }
+        name
+    }
+
+    /// Generate the indices of the words in a new petname.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let petnames = petname::Petnames::default();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let indices = petnames.generate_indices(&mut rng, 3);
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let name = petnames.name_from_indices(&indices, "-").unwrap();
+    /// ```
+    ///
+    /// Each index is the position of a word in its list, so a name can be
+    /// stored compactly and rebuilt with
+    /// [`name_from_indices`][`Petnames::name_from_indices`] – provided the word
+    /// lists are the same.
+    ///
+    pub fn generate_indices<RNG>(&self, rng: &mut RNG, words: u8) -> Vec<usize>
+    where
+        RNG: rand::Rng,
+    {
+        Lists::new(self, words)
+            .filter(|list| !list.is_empty())
+            .map(|list| rng.random_range(0..list.len()))
+            .collect()
+    }
+
+    /// Rebuild a petname from the indices of its words; see
+    /// [`generate_indices`][`Petnames::generate_indices`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let petnames = petname::Petnames::init("happy sad", "very", "cat dog");
+    /// assert_eq!(Some("very-sad-cat".to_string()), petnames.name_from_indices(&[0, 1, 0], "-"));
+    /// assert_eq!(None, petnames.name_from_indices(&[0, 2, 0], "-"));
+    /// ```
+    ///
+    /// There is one index for each word, so this builds a name of
+    /// `indices.len()` words. This returns `None` if an index is out of range
+    /// for its list or there are more than 255 indices. The name is only the
+    /// original if no list it was built from was empty; empty lists are
+    /// skipped when generating, which shifts the remaining indices.
+    ///
+    pub fn name_from_indices<SEP>(&self, indices: &[usize], separator: SEP) -> Option<String>
+    where
+        SEP: SeparatorStrategy,
+    {
+        let words = core::convert::TryFrom::try_from(indices.len()).ok()?;
+        let mut name = String::new();
+        for (index, (list, &word)) in Lists::new(self, words).zip(indices).enumerate() {
+            if index > 0 {
+                name.push_str(separator.separator(index - 1));
+            }
+            name.push_str(list.get(word)?);
+        }
+        Some(name)
+    }
+
+    /// The petname at `index` in an ordering of all petnames shuffled by
+    /// `seed`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let petnames = petname::Petnames::init("happy sad", "very", "cat dog");
+    /// let names: Vec<String> = (0..4)
+    ///     .filter_map(|index| petnames.name_at(42, index, 2, "-"))
+    ///     .collect();
+    /// assert_eq!(4, names.len());
+    /// assert_eq!(None, petnames.name_at(42, 4, 2, "-"));
+    /// ```
+    ///
+    /// Each seed puts all [`cardinality`][`Petnames::cardinality`] names
+    /// into its own order, so indices from 0 up to the cardinality each give
+    /// a different name, covering them all. No state is kept: the millionth
+    /// name of a seed is found as quickly as the first, which makes it easy
+    /// to resume generation from a counter. This returns `None` if `index` is
+    /// not less than the cardinality, or if the cardinality exceeds
+    /// `u128::MAX`.
+    ///
+    /// The order is a keyed permutation, not a cryptographic one: it looks
+    /// random, but should not be relied upon to keep the next name secret.
+    ///
+    pub fn name_at<SEP>(&self, seed: u64, index: u128, words: u8, separator: SEP) -> Option<String>
+    where
+        SEP: SeparatorStrategy,
+    {
+        let cardinality = self.cardinality_checked(words)?;
+        if index >= cardinality {
+            return None;
+        }
+        // Read the permuted index as a mixed-radix number, with a digit for
+        // each word.
+        let mut position = permute(seed, index, cardinality);
+        let mut name = String::new();
+        for (index, list) in Lists::new(self, words).enumerate() {
+            let len = list.len() as u128;
+            if index > 0 {
+                name.push_str(separator.separator(index - 1));
+            }
+            name.push_str(list[(position % len) as usize]);
+            position /= len;
+        }
+        Some(name)
+    }
+
+    /// Generate a new petname, transforming the name / noun with `transform`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let petnames = petname::Petnames::init("happy", "very", "cat");
+    /// # #[cfg(feature = "std_rng")]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(feature = "std_rng")]
+    /// let name = petnames.generate_with_noun_transform(&mut rng, 2, "-", |noun| {
+    ///     noun.to_uppercase()
+    /// });
+    /// # #[cfg(feature = "std_rng")]
+    /// assert_eq!("happy-CAT", name);
+    /// ```
+    ///
+    /// Adverbs and adjectives are left as they are.
+    ///
+    pub fn generate_with_noun_transform<RNG, SEP, F>(
+        &self,
+        rng: &mut RNG,
+        words: u8,
+        separator: SEP,
+        mut transform: F,
+    ) -> String
+    where
+        RNG: rand::Rng,
+        SEP: SeparatorStrategy,
+        F: FnMut(&str) -> String,
+    {
+        let mut name = String::new();
+        for (index, (kind, word)) in self.generate_tagged(rng, words).into_iter().enumerate() {
+            if index > 0 {
+                name.push_str(separator.separator(index - 1));
+            }
+            match kind {
+                WordKind::Name => name.push_str(&transform(word)),
+                _ => name.push_str(word),
+            }
+        }
+        name
+    }
+
+    /// Generate a new petname with the name / noun in the plural.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let petnames = petname::Petnames::init("happy", "very", "cat");
+    /// # #[cfg(feature = "std_rng")]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(feature = "std_rng")]
+    /// assert_eq!("very-happy-cats", petnames.generate_plural_noun(&mut rng, 3, "-"));
+    /// ```
+    ///
+    /// This is naïve, English-only pluralisation: an "s" is appended to the
+    /// noun, so "fox" becomes "foxs". Use
+    /// [`generate_with_noun_transform`][`Petnames::generate_with_noun_transform`]
+    /// to supply better rules.
+    ///
+    pub fn generate_plural_noun<RNG, SEP>(&self, rng: &mut RNG, words: u8, separator: SEP) -> String
+    where
+        RNG: rand::Rng,
+        SEP: SeparatorStrategy,
+    {
+        self.generate_with_noun_transform(rng, words, separator, |noun| {
+            let mut plural = String::with_capacity(noun.len() + 1);
+            plural.push_str(noun);
+            plural.push('s');
+            plural
+        })
+    }
+
+    /// Generate a new petname with the given capitalisation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// petname::Petnames::default().generate_cased(&mut rng, 3, "-", petname::Casing::Title);
+    /// ```
+    ///
+    pub fn generate_cased<RNG, SEP>(
+        &self,
+        rng: &mut RNG,
+        words: u8,
+        separator: SEP,
+        casing: Casing,
+    ) -> String
+    where
+        RNG: rand::Rng,
+        SEP: SeparatorStrategy,
+    {
+        let mut name = String::new();
+        for (index, (kind, word)) in self.generate_tagged(rng, words).into_iter().enumerate() {
+            if index > 0 {
+                name.push_str(separator.separator(index - 1));
+            }
+            casing.apply(index, kind, word, &mut name);
+        }
+        name
+    }
+
+    /// Generate a new petname in which names / nouns are proper nouns, e.g.
+    /// "happily-brave-London".
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use petname::{Casing, Petnames};
+    /// let petnames = Petnames::init("Brave", "happily", "london");
+    /// # #[cfg(feature = "std_rng")]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(feature = "std_rng")]
+    /// assert_eq!(
+    ///     "happily-brave-London",
+    ///     petnames.generate_proper(&mut rng, 3, "-", Casing::Lower),
+    /// );
+    /// ```
+    ///
+    /// This suits dictionaries of actual names, of people or places. Adverbs
+    /// and adjectives are cased with `casing`, as in
+    /// [`generate_cased`][`Petnames::generate_cased`], but a name / noun
+    /// always has its first letter in upper case, whatever `casing` says. The
+    /// rest of a name is kept as written, so "McDonald" is not flattened to
+    /// "Mcdonald". With [`Casing::Title`] every word is capitalised anyway,
+    /// so the only difference from `generate_cased` is that names keep their
+    /// inner capitals.
+    ///
+    pub fn generate_proper<RNG, SEP>(
+        &self,
+        rng: &mut RNG,
+        words: u8,
+        separator: SEP,
+        casing: Casing,
+    ) -> String
+    where
+        RNG: rand::Rng,
+        SEP: SeparatorStrategy,
+    {
+        let mut name = String::new();
+        for (index, (kind, word)) in self.generate_tagged(rng, words).into_iter().enumerate() {
+            if index > 0 {
+                name.push_str(separator.separator(index - 1));
+            }
+            if kind == WordKind::Name {
+                let mut chars = word.chars();
+                if let Some(first) = chars.next() {
+                    name.extend(first.to_uppercase());
+                    name.push_str(chars.as_str());
+                }
+            } else {
+                casing.apply(index, kind, word, &mut name);
+            }
+        }
+        name
+    }
+
+    /// Generate a new petname in both display and slug forms.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let petnames = petname::Petnames::init("little", "happy", "cat");
+    /// # #[cfg(feature = "std_rng")]
+    /// let (display, slug) = petnames.generate_name_and_slug(&mut rand::rng(), 3);
+    /// # #[cfg(feature = "std_rng")]
+    /// assert_eq!(("Happy Little Cat", "happy-little-cat"), (&*display, &*slug));
+    /// ```
+    ///
+    /// Both come from the same words: the display form is in title case and
+    /// separated by spaces, and the slug is in lower case and separated by
+    /// hyphens.
+    ///
+    pub fn generate_name_and_slug<RNG>(&self, rng: &mut RNG, words: u8) -> (String, String)
+    where
+        RNG: rand::Rng,
+    {
+        let mut display = String::new();
+        let mut slug = String::new();
+        for (index, (kind, word)) in self.generate_tagged(rng, words).into_iter().enumerate() {
+            if index > 0 {
+                display.push(' ');
+                slug.push('-');
+            }
+            Casing::Title.apply(index, kind, word, &mut display);
+            Casing::Lower.apply(index, kind, word, &mut slug);
+        }
+        (display, slug)
+    }
+
+    /// Generate a new petname followed by a random emoji.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let petnames = petname::Petnames::init("happy", "very", "cat");
+    /// # #[cfg(feature = "std_rng")]
+    /// let name = petnames.generate_with_emoji(&mut rand::rng(), 2, "-", &["🐱"]);
+    /// # #[cfg(feature = "std_rng")]
+    /// assert_eq!("happy-cat🐱", name);
+    /// ```
+    ///
+    /// This is purely cosmetic. The emoji is chosen from `emojis` and appended
+    /// directly, without a separator; if `emojis` is empty, nothing is
+    /// appended.
+    ///
+    pub fn generate_with_emoji<RNG, SEP>(
+        &self,
+        rng: &mut RNG,
+        words: u8,
+        separator: SEP,
+        emojis: &[&str],
+    ) -> String
+    where
+        RNG: rand::Rng,
+        SEP: SeparatorStrategy,
+    {
+        let mut name = self.generate(rng, words, separator);
+        if let Some(emoji) = emojis.choose(rng) {
+            name.push_str(emoji);
+        }
+        name
+    }
+
+    /// Generate the petname for `key`, which is always the same for the same
+    /// key.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "default_dictionary")]
+    /// let petnames = petname::Petnames::default();
+    /// # #[cfg(feature = "default_dictionary")]
+    /// assert_eq!(
+    ///     petnames.name_for("db-01.example.com", 3, "-"),
+    ///     petnames.name_for("db-01.example.com", 3, "-"),
+    /// );
+    /// ```
+    ///
+    /// This gives opaque identifiers, like UUIDs or hostnames, stable and
+    /// human-readable aliases. The key is hashed with [FNV-1a][fnv] to seed a
+    /// small deterministic generator, and each word is picked by scaling that
+    /// generator's output to the length of its list. None of this depends on
+    /// `rand`, so names stay the same from one version of `rand` to the next.
+    ///
+    /// A name depends on the key, the word lists – including their order –
+    /// and the adverb policy; change any of those and the name will change.
+    /// Note that the [`Hash`] implementations of some types, like `usize`,
+    /// differ between platforms. Different keys can map to the same name,
+    /// and will do so more often when the cardinality is low.
+    ///
+    /// [fnv]: http://www.isthe.com/chongo/tech/comp/fnv/
+    pub fn name_for<H, SEP>(&self, key: H, words: u8, separator: SEP) -> String
+    where
+        H: Hash,
+        SEP: SeparatorStrategy,
+    {
+        let mut hash = Fnv1a::new();
+        key.hash(&mut hash);
+        use rand::RngCore;
+        let mut rng = SplitMix64(hash.finish());
+        let mut name = String::new();
+        for (index, word) in Lists::new(self, words)
+            .filter_map(|list| {
+                // Multiply and shift, rather than `choose`, which could change.
+                let index = (u128::from(rng.next_u64()) * list.len() as u128) >> 64;
+                list.get(index as usize)
+            })
+            .enumerate()
+        {
+            if index > 0 {
+                name.push_str(separator.separator(index - 1));
+            }
+            name.push_str(word);
+        }
+        name
+    }
+
+    /// Generate a new petname followed by a check character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let petnames = petname::Petnames::init("happy", "very", "cat");
+    /// # #[cfg(feature = "std_rng")]
+    /// let name = petnames.generate_with_checksum(&mut rand::rng(), 2, "-");
+    /// # #[cfg(feature = "std_rng")]
+    /// assert_eq!("happy-cat-m", name);
+    /// # #[cfg(feature = "std_rng")]
+    /// assert!(petname::verify_checksum(&name));
+    /// ```
+    ///
+    /// The check character follows the last separator. It is the sum of the
+    /// Unicode code points of everything before it – words and separators –
+    /// modulo 26, written as a letter from "a" to "z". Check a name with
+    /// [`verify_checksum`].
+    ///
+    /// This catches any one lowercase ASCII letter mistyped as another, but
+    /// it is not a strong check: swapping two characters, for example, goes
+    /// unnoticed, and other errors go unnoticed one time in 26.
+    ///
+    pub fn generate_with_checksum<RNG, SEP>(
+        &self,
+        rng: &mut RNG,
+        words: u8,
+        separator: SEP,
+    ) -> String
+    where
+        RNG: rand::Rng,
+        SEP: SeparatorStrategy,
+    {
+        let words = self.generate_tagged(rng, words);
+        let mut name = String::new();
+        for (index, (_, word)) in words.iter().enumerate() {
+            name.push_str(word);
+            name.push_str(separator.separator(index));
+        }
+        let check = checksum(&name);
+        name.push(check);
+        name
+    }
+
+    /// Generate a new petname with a random number of separators in each gap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// petname::Petnames::default().generate_variable_separator(&mut rng, 3, ".", 1, 3);
+    /// ```
+    ///
+    /// Each gap gets its own run of between `min` and `max` – inclusive –
+    /// repetitions of the separator, e.g. "happy..little.cat". This is purely
+    /// for visual variety.
+    ///
+    /// # Panics
+    ///
+    /// If `min` is greater than `max`.
+    ///
+    pub fn generate_variable_separator<RNG, SEP>(
+        &self,
+        rng: &mut RNG,
+        words: u8,
+        separator: SEP,
+        min: usize,
+        max: usize,
+    ) -> String
+    where
+        RNG: rand::Rng,
+        SEP: SeparatorStrategy,
+    {
+        assert!(min <= max, "separator repetitions {} > {}", min, max);
+        let mut name = String::new();
+        for (index, (_, word)) in self.generate_tagged(rng, words).into_iter().enumerate() {
+            if index > 0 {
+                let repetitions = rng.random_range(min..=max);
+                for _ in 0..repetitions {
+                    name.push_str(separator.separator(index - 1));
+                }
+            }
+            name.push_str(word);
+        }
+        name
+    }
+
+    /// Generate a new petname that is a valid Rust identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let petnames = petname::Petnames::init("Mut", "very", "Cat's");
+    /// # #[cfg(feature = "std_rng")]
+    /// let ident = petnames.generate_ident(&mut rand::rng(), 2);
+    /// # #[cfg(feature = "std_rng")]
+    /// assert_eq!("mut_cats", ident);
+    /// # #[cfg(feature = "std_rng")]
+    /// assert!(petname::is_valid_ident(&ident));
+    /// ```
+    ///
+    /// Words are lowercased, stripped of anything but ASCII letters, digits,
+    /// and underscores, then joined with underscores. Words left empty are
+    /// dropped. A name that would begin with a digit is prefixed with an
+    /// underscore, and a name that is a keyword, like `fn`, is suffixed with
+    /// one. If no words remain the name is `unnamed`. The result always
+    /// satisfies [`is_valid_ident`].
+    ///
+    pub fn generate_ident<RNG>(&self, rng: &mut RNG, words: u8) -> String
+    where
+        RNG: rand::Rng,
+    {
+        let mut ident = String::new();
+        for (_, word) in self.generate_tagged(rng, words) {
+            let word: String = word
+                .to_lowercase()
+                .chars()
+                .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+                .collect();
+            if !word.is_empty() {
+                if !ident.is_empty() {
+                    ident.push('_');
+                }
+                ident.push_str(&word);
+            }
+        }
+        if ident.is_empty() || ident.chars().all(|c| c == '_') {
+            ident.insert_str(0, "unnamed");
+        } else if ident.starts_with(|c: char| c.is_ascii_digit()) {
+            ident.insert(0, '_');
+        } else if RUST_KEYWORDS.contains(&ident.as_str()) {
+            ident.push('_');
+        }
+        ident
+    }
+
+    /// Generate a new petname, borrowing from the word lists when possible.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// petname::Petnames::default().generate_cow(&mut rng, 1, ":");
+    /// ```
+    ///
+    /// This is like `generate` but a name of 0 or 1 words – which needs no
+    /// separator – is returned as [`Cow::Borrowed`] without allocating.
+    ///
+    pub fn generate_cow<RNG, SEP>(&self, rng: &mut RNG, words: u8, separator: SEP) -> Cow<'a, str>
+    where
+        RNG: rand::Rng,
+        SEP: SeparatorStrategy,
+    {
+        match words {
+            0 => Cow::Borrowed(""),
+            1 => Cow::Borrowed(self.names.choose(rng).copied().unwrap_or("")),
+            _ => Cow::Owned(self.generate(rng, words, separator)),
+        }
+    }
+
+    /// Generate a new petname, weighting words so that each stem is equally
+    /// likely to be chosen.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// petname::Petnames::default().generate_stem_weighted(&mut rng, 3, "-", 4);
+    /// ```
+    ///
+    /// A word's stem is simply its first `stem` characters; this is a crude
+    /// heuristic that knows nothing of language. Words sharing a stem split a
+    /// single unit of weight between them, so a list with "cat", "cats", and
+    /// "dog" picks "dog" half of the time rather than a third. Stems are
+    /// computed on every call, so this is slower than `generate`.
+    ///
+    pub fn generate_stem_weighted<RNG, SEP>(
+        &self,
+        rng: &mut RNG,
+        words: u8,
+        separator: SEP,
+        stem: usize,
+    ) -> String
+    where
+        RNG: rand::Rng,
+        SEP: SeparatorStrategy,
+    {
+        Lists::new(self, words)
+            .filter_map(|list| choose_by_stem(list, stem, rng))
+            .enumerate()
+            .fold(String::new(), |mut name, (index, word)| {
+                if index > 0 {
+                    name.push_str(separator.separator(index - 1));
+                }
+                name.push_str(word);
+                name
+            })
+    }
+
+    /// Generate a new petname with a length close to `target`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// petname::Petnames::default().generate_near_length(&mut rng, 3, "-", 20, 2, petname::DEFAULT_RETRIES);
+    /// ```
+    ///
+    /// Names are generated until one is found whose length – in characters,
+    /// not bytes – is within `target ± tolerance`. This gives up and returns
+    /// `None` after `retries` attempts – [`DEFAULT_RETRIES`] is a sensible
+    /// choice – so it will also return `None` when no such name is possible.
+    ///
+    pub fn generate_near_length<RNG, SEP>(
+        &self,
+        rng: &mut RNG,
+        words: u8,
+        separator: SEP,
+        target: usize,
+        tolerance: usize,
+        retries: usize,
+    ) -> Option<String>
+    where
+        RNG: rand::Rng,
+        SEP: SeparatorStrategy,
+    {
+        let range = target.saturating_sub(tolerance)..=target.saturating_add(tolerance);
+        self.generate_matching(rng, words, separator, retries, |name| {
+            range.contains(&name.chars().count())
+        })
+    }
+
+    /// Generate a new petname of at most `max_len` characters, falling back
+    /// to each of `separators` in turn until one fits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let petnames = petname::Petnames::init("happy", "very", "cat");
+    /// # #[cfg(feature = "std_rng")]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(feature = "std_rng")]
+    /// assert_eq!(
+    ///     Some(("happy-cat".to_string(), "-")),
+    ///     petnames.generate_max_len_with_fallback(&mut rng, 2, &["--", "-", ""], 9, petname::DEFAULT_RETRIES),
+    /// );
+    /// ```
+    ///
+    /// Separators are tried in the order given, so put the preferred one
+    /// first and the shortest, often `""`, last. For each separator, up to
+    /// `retries` names are generated – [`DEFAULT_RETRIES`] is a sensible
+    /// choice – as in
+    /// [`generate_matching`][`Petnames::generate_matching`], before moving on
+    /// to the next. The first name that fits is returned along with the
+    /// separator used. This returns `None` if no name fits with any
+    /// separator.
+    ///
+    pub fn generate_max_len_with_fallback<'s, RNG>(
+        &self,
+        rng: &mut RNG,
+        words: u8,
+        separators: &[&'s str],
+        max_len: usize,
+        retries: usize,
+    ) -> Option<(String, &'s str)>
+    where
+        RNG: rand::Rng,
+    {
+        separators.iter().find_map(|&separator| {
+            self.generate_matching(rng, words, separator, retries, |name| {
+                name.chars().count() <= max_len
+            })
+            .map(|name| (name, separator))
+        })
+    }
+
+    /// Generate a new petname with at most `max` syllables, by the estimate
+    /// of [`estimate_syllables`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// petname::Petnames::default().generate_max_syllables(&mut rng, 2, "-", 4, petname::DEFAULT_RETRIES);
+    /// ```
+    ///
+    /// Syllables are estimated for each word and summed, so separators do not
+    /// matter. The estimate is approximate and English-biased; use this to
+    /// favour short, sayable names, not to guarantee them. Names are generated
+    /// until one fits, giving up and returning `None` after `retries`
+    /// attempts; [`DEFAULT_RETRIES`] is a sensible choice.
+    ///
+    pub fn generate_max_syllables<RNG, SEP>(
+        &self,
+        rng: &mut RNG,
+        words: u8,
+        separator: SEP,
+        max: usize,
+        retries: usize,
+    ) -> Option<String>
+    where
+        RNG: rand::Rng,
+        SEP: SeparatorStrategy,
+    {
+        (0..retries)
+            .map(|_| self.generate_tagged(rng, words))
+            .find(|tagged| {
+                let syllables: usize = tagged
+                    .iter()
+                    .map(|(_, word)| estimate_syllables(word))
+                    .sum();
+                syllables <= max
+            })
+            .map(|tagged| {
+                let mut name = String::new();
+                for (index, (_, word)) in tagged.into_iter().enumerate() {
+                    if index > 0 {
+                        name.push_str(separator.separator(index - 1));
+                    }
+                    name.push_str(word);
+                }
+                name
+            })
+    }
+
+    /// Generate a new petname that satisfies `predicate`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// petname::Petnames::default().generate_matching(&mut rng, 3, "-", 10, |name| name.len() < 20);
+    /// ```
+    ///
+    /// This makes at most `retries` attempts before giving up and returning
+    /// `None`. This is the building block for constrained generation, like
+    /// [`generate_near_length`][`Petnames::generate_near_length`], which use
+    /// [`DEFAULT_RETRIES`]; call this directly to trade latency for a better
+    /// chance of success, or vice versa.
+    ///
+    pub fn generate_matching<RNG, SEP, F>(
+        &self,
+        rng: &mut RNG,
+        words: u8,
+        separator: SEP,
+        retries: usize,
+        mut predicate: F,
+    ) -> Option<String>
+    where
+        RNG: rand::Rng,
+        SEP: SeparatorStrategy,
+        F: FnMut(&str) -> bool,
+    {
+        (0..retries)
+            .map(|_| self.generate(rng, words, &separator))
+            .find(|name| predicate(name))
+    }
+
+    /// Generate a new petname that favours words from a theme.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// let space = ["cosmic", "lunar", "solar", "comet", "orbit", "star"];
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// petname::Petnames::default().generate_themed(&mut rng, 2, "-", &space, 0.8);
+    /// ```
+    ///
+    /// For each word, with probability `theme_prob`, the word is chosen from
+    /// those in both its list and `theme_words`; otherwise it is chosen from
+    /// the whole list, as in [`generate`][`Petnames::generate`]. Theme words
+    /// not in a list are never used in that position, so an adjective in the
+    /// theme will not appear as a name.
+    ///
+    /// When no theme word is in a list, words in that position always come
+    /// from the whole list. A `theme_prob` of 0.0 or less never favours the
+    /// theme, and 1.0 or more always does, where it can.
+    ///
+    pub fn generate_themed<RNG, SEP>(
+        &self,
+        rng: &mut RNG,
+        words: u8,
+        separator: SEP,
+        theme_words: &[&str],
+        theme_prob: f64,
+    ) -> String
+    where
+        RNG: rand::Rng,
+        SEP: SeparatorStrategy,
+    {
+        let theme: BTreeSet<&str> = theme_words.iter().copied().collect();
+        let mut name = String::new();
+        for (index, list) in Lists::new(self, words)
+            .filter(|list| !list.is_empty())
+            .enumerate()
+        {
+            let themed: Words = list
+                .iter()
+                .copied()
+                .filter(|word| theme.contains(word))
+                .collect();
+            let word = if rng.random::<f64>() < theme_prob && !themed.is_empty() {
+                themed.choose(rng)
+            } else {
+                list.choose(rng)
+            };
+            if index > 0 {
+                name.push_str(separator.separator(index - 1));
+            }
+            name.push_str(word.copied().unwrap_or_default());
+        }
+        name
+    }
+
+    /// Generate a new petname in which no two adjacent words begin with the
+    /// same letter.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// petname::Petnames::default().generate_no_adjacent_alliteration(&mut rng, 3, "-", 10);
+    /// ```
+    ///
+    /// This avoids names like "happy-hungry-cat" while still allowing, say,
+    /// "happy-little-hen". Each word is chosen in turn, and is re-sampled up
+    /// to `retries` times until its initial differs from that of the word
+    /// before. Initials are compared exactly, so "Happy" and "hungry" do not
+    /// clash.
+    ///
+    /// This returns `None` if any word cannot be chosen within `retries`
+    /// attempts, which is certain if, for example, every word in a list
+    /// begins with the same letter as the only word in the list before it.
+    /// Empty lists are skipped, as in [`generate`][`Petnames::generate`].
+    ///
+    pub fn generate_no_adjacent_alliteration<RNG, SEP>(
+        &self,
+        rng: &mut RNG,
+        words: u8,
+        separator: SEP,
+        retries: usize,
+    ) -> Option<String>
+    where
+        RNG: rand::Rng,
+        SEP: SeparatorStrategy,
+    {
+        let mut name = String::new();
+        let mut previous: Option<char> = None;
+        for (index, list) in Lists::new(self, words)
+            .filter(|list| !list.is_empty())
+            .enumerate()
+        {
+            let word = (0..retries)
+                .filter_map(|_| list.choose(rng))
+                .find(|word| previous.is_none() || word.chars().next() != previous)?;
+            if index > 0 {
+                name.push_str(separator.separator(index - 1));
+            }
+            name.push_str(word);
+            previous = word.chars().next();
+        }
+        Some(name)
+    }
+
+    /// Generate a new petname that passes an external `check`, e.g. that a
+    /// host name is not yet in DNS.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let taken = ["happy-cat", "sad-dog"];
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// petname::Petnames::default().generate_validated(&mut rng, 2, "-", |name| !taken.contains(&name), 10);
+    /// ```
+    ///
+    /// This is [`generate_matching`][`Petnames::generate_matching`] by
+    /// another name, with the arguments in an order that reads better when
+    /// `check` is a long closure: `check` is called with each new name, at
+    /// most `max_attempts` times, and the first name it accepts is returned.
+    /// If none is accepted this returns `None`.
+    ///
+    pub fn generate_validated<RNG, SEP, F>(
+        &self,
+        rng: &mut RNG,
+        words: u8,
+        separator: SEP,
+        check: F,
+        max_attempts: usize,
+    ) -> Option<String>
+    where
+        RNG: rand::Rng,
+        SEP: SeparatorStrategy,
+        F: FnMut(&str) -> bool,
+    {
+        self.generate_matching(rng, words, separator, max_attempts, check)
+    }
+
+    /// Generate a batch of up to `n` distinct petnames.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let names = petname::Petnames::default().distinct_batch(&mut rng, 3, "-", 10);
+    /// ```
+    ///
+    /// This returns `n` names, or fewer when the [cardinality] is less than
+    /// `n`. Names are first generated at random, and duplicates discarded.
+    /// Once [`DEFAULT_RETRIES`] duplicates are generated in a row, the rest
+    /// are instead taken from a random ordering of every name – see
+    /// [`name_at`][`Petnames::name_at`] – skipping those already in the
+    /// batch. Uniqueness is only within the batch; use
+    /// [`iter_non_repeating`][`Petnames::iter_non_repeating`] for an
+    /// unbounded supply of unique names.
+    ///
+    /// [cardinality]: Petnames::cardinality
+    ///
+    pub fn distinct_batch<RNG, SEP>(
+        &self,
+        rng: &mut RNG,
+        words: u8,
+        separator: SEP,
+        n: usize,
+    ) -> Vec<String>
+    where
+        RNG: rand::Rng,
+        SEP: SeparatorStrategy,
+    {
+        let target = self.cardinality(words).min(n as u128) as usize;
+        let mut seen = BTreeSet::new();
+        let mut names = Vec::with_capacity(target);
+        let mut duplicates = 0;
+        while names.len() < target && duplicates < DEFAULT_RETRIES {
+            let name = self.generate(rng, words, &separator);
+            if seen.insert(name.clone()) {
+                names.push(name);
+                duplicates = 0;
+            } else {
+                duplicates += 1;
+            }
+        }
+        if names.len() < target {
+            // Random names keep colliding, so walk every name without repeats.
+            let seed = rng.random();
+            let ordered = (0..self.cardinality(words))
+                .map_while(|index| self.name_at(seed, index, words, &separator));
+            for name in ordered {
+                if names.len() == target {
+                    break;
+                }
+                if seen.insert(name.clone()) {
+                    names.push(name);
+                }
+            }
+        }
+        names
+    }
+
+    /// Generate a new petname for which `excluded` returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// let mut existing = std::collections::HashSet::new();
+    /// existing.insert("happy-cat".to_string());
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let name = petname::Petnames::default()
+    ///     .generate_excluding(&mut rng, 2, "-", |name| existing.contains(name));
+    /// ```
+    ///
+    /// This avoids names that are already taken, e.g. by rows in a database.
+    /// `excluded` can look names up in a `HashSet`, a `BTreeSet`, or anything
+    /// else, without copying them.
+    ///
+    /// Names are first generated at random, up to [`DEFAULT_RETRIES`] times.
+    /// If every one is excluded, the names are instead walked in a random
+    /// order, without repeats – see [`name_at`][`Petnames::name_at`] – until
+    /// one is found that is not excluded. Hence this returns `None` only when
+    /// every possible name is excluded – or when a word list needed is empty
+    /// and each shorter name is excluded.
+    ///
+    pub fn generate_excluding<RNG, F>(
+        &self,
+        rng: &mut RNG,
+        words: u8,
+        separator: &str,
+        mut excluded: F,
+    ) -> Option<String>
+    where
+        RNG: rand::Rng,
+        F: FnMut(&str) -> bool,
+    {
+        if let Some(name) = (0..DEFAULT_RETRIES)
+            .map(|_| self.generate(rng, words, separator))
+            .find(|name| !excluded(name))
+        {
+            return Some(name);
+        }
+        let seed = rng.random();
+        (0..self.cardinality(words))
+            .map_while(|index| self.name_at(seed, index, words, separator))
+            .find(|name| !excluded(name))
+    }
+
+    /// Generate a single new petname.
+    ///
+    /// This is like `generate` but uses `rand::rng` as the random
+    /// source. For efficiency use `generate` when creating multiple names, or
+    /// when you want to use a custom source of randomness.
+    #[cfg(feature = "std_rng")]
+    pub fn generate_one<SEP>(&self, words: u8, separator: SEP) -> String
+    where
+        SEP: SeparatorStrategy,
+    {
+        self.generate(&mut rand::rng(), words, separator)
+    }
+
+    /// Iterator yielding petnames.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let petnames = petname::Petnames::default();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut iter = petnames.iter(&mut rng, 4, "_");
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// println!("name: {}", iter.next().unwrap());
+    /// ```
+    ///
+    pub fn iter<RNG>(&self, rng: &'a mut RNG, words: u8, separator: &str) -> Names<'_, RNG>
+    where
+        RNG: rand::Rng,
+    {
+        Names {
+            petnames: self,
+            rng,
+            words,
+            separator: separator.to_string(),
+        }
+    }
+
+    /// Iterator yielding petnames, each paired with its ordinal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let petnames = petname::Petnames::default();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// for (ordinal, name) in petnames.iter_enumerated(&mut rng, 2, "-", 1).take(3) {
+    ///     println!("{}: {}", ordinal, name);
+    /// }
+    /// ```
+    ///
+    /// Ordinals count up from `base`, so the Nth name can be referred to,
+    /// e.g. in logs. This is like `iter(..).enumerate()` with an offset. The
+    /// iterator ends if the ordinals would overflow `usize`.
+    ///
+    pub fn iter_enumerated<RNG>(
+        &self,
+        rng: &'a mut RNG,
+        words: u8,
+        separator: &str,
+        base: usize,
+    ) -> impl Iterator<Item = (usize, String)> + '_
+    where
+        RNG: rand::Rng,
+    {
+        (base..=usize::MAX).zip(self.iter(rng, words, separator))
+    }
+
+    /// Iterator yielding reproducible petnames from `seed`, starting at
+    /// position `offset` in the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let petnames = petname::Petnames::init("happy sad", "very", "cat dog");
+    /// let mut names = petnames.iter_seeded_offset(42, 0, 2, "-").skip(3);
+    /// assert_eq!(
+    ///     names.next(),
+    ///     petnames.iter_seeded_offset(42, 3, 2, "-").next(),
+    /// );
+    /// ```
+    ///
+    /// The same seed always gives the same sequence for the same word lists
+    /// and adverb policy, and `offset` skips that many names into it. This
+    /// lets, say, node `i` of a cluster take a reproducible sequence of its
+    /// own from a shared seed. Sequences at different offsets are the same
+    /// sequence shifted, so they overlap: node 0's sixth name is node 5's
+    /// first. Nor is any sequence free of repeats. To avoid collisions
+    /// between nodes, see [`shard`][`Petnames::shard`].
+    ///
+    /// Skipped names are generated and discarded, so a large offset takes
+    /// time in proportion.
+    ///
+    pub fn iter_seeded_offset(
+        &self,
+        seed: u64,
+        offset: usize,
+        words: u8,
+        separator: &str,
+    ) -> impl Iterator<Item = String> + '_ {
+        let (mut rng, separator) = (SplitMix64(seed), separator.to_string());
+        core::iter::repeat_with(move || self.generate(&mut rng, words, separator.as_str()))
+            .skip(offset)
+    }
+
+    /// Iterator yielding petnames up to – and including – the first that
+    /// satisfies `predicate`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let petnames = petname::Petnames::default();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let attempts: Vec<String> =
+    ///     petnames.iter_until(&mut rng, 2, "-", |name| name.starts_with('b')).collect();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// assert!(attempts.last().unwrap().starts_with('b'));
+    /// ```
+    ///
+    /// This is like `iter().find(predicate)` except that every attempt is
+    /// yielded, e.g. for logging. If no petname can satisfy `predicate` this
+    /// will run forever.
+    ///
+    pub fn iter_until<RNG, F>(
+        &self,
+        rng: &'a mut RNG,
+        words: u8,
+        separator: &str,
+        mut predicate: F,
+    ) -> impl Iterator<Item = String> + '_
+    where
+        RNG: rand::Rng,
+        F: FnMut(&str) -> bool + 'a,
+    {
+        let mut done = false;
+        self.iter(rng, words, separator).take_while(move |name| {
+            let more = !done;
+            done = done || predicate(name);
+            more
+        })
+    }
+
+    /// Iterator yielding petnames with a random number of words each.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let petnames = petname::Petnames::default();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let names: Vec<String> = petnames.iter_variable_words(&mut rng, 2, 4, "-").take(10).collect();
+    /// ```
+    ///
+    /// For each name the number of words is picked uniformly between
+    /// `min_words` and `max_words` – inclusive – so a stream can mix, say,
+    /// "happy-cat" and "very-happy-cat". Shorter names have fewer
+    /// possibilities, so they repeat more often. There is no non-repeating
+    /// counterpart: [`iter_non_repeating`][`Petnames::iter_non_repeating`]
+    /// walks the names of one fixed number of words.
+    ///
+    /// # Panics
+    ///
+    /// If `min_words` is greater than `max_words`.
+    ///
+    pub fn iter_variable_words<RNG>(
+        &self,
+        rng: &'a mut RNG,
+        min_words: u8,
+        max_words: u8,
+        separator: &str,
+    ) -> impl Iterator<Item = String> + '_
+    where
+        RNG: rand::Rng,
+    {
+        assert!(
+            min_words <= max_words,
+            "words {} > {}",
+            min_words,
+            max_words
+        );
+        let separator = separator.to_string();
+        core::iter::from_fn(move || {
+            let words = rng.random_range(min_words..=max_words);
+            Some(self.generate(rng, words, &separator))
+        })
+    }
+
+    /// Iterator yielding petnames that use every name / noun in turn.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let petnames = petname::Petnames::default();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let names: Vec<String> = petnames.iter_balanced(&mut rng, 3, "-").take(100).collect();
+    /// ```
+    ///
+    /// Names are round-robined: they are shuffled, then each is used once
+    /// before they are shuffled again for the next round. Adverbs and
+    /// adjectives are chosen at random as usual. In any batch, then, no name
+    /// is used more than once more than any other, which balances a short
+    /// names list against long adjective and adverb lists.
+    ///
+    pub fn iter_balanced<RNG>(
+        &'a self,
+        rng: &'a mut RNG,
+        words: u8,
+        separator: &str,
+    ) -> impl Iterator<Item = String> + 'a
+    where
+        RNG: rand::Rng,
+    {
+        let separator = separator.to_string();
+        let mut round = self.names.clone();
+        let mut next = round.len();
+        core::iter::from_fn(move || {
+            let mut name = String::new();
+            for (index, (kind, list)) in Lists::new(self, words)
+                .tagged()
+                .filter(|(_, list)| !list.is_empty())
+                .enumerate()
+            {
+                if index > 0 {
+                    name.push_str(&separator);
+                }
+                let word = match kind {
+                    WordKind::Name => {
+                        if next >= round.len() {
+                            round.shuffle(rng);
+                            next = 0;
+                        }
+                        next += 1;
+                        round[next - 1]
+                    }
+                    _ => list.choose(rng).copied().unwrap_or_default(),
+                };
+                name.push_str(word);
+            }
+            Some(name)
+        })
+    }
+
+    /// Iterator yielding pairs of different petnames.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let petnames = petname::Petnames::default();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let (source, target) = petnames.iter_pairs(&mut rng, 2, "-").next().unwrap();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// assert_ne!(source, target);
+    /// ```
+    ///
+    /// Both names in a pair come from the same `rng`, so there is no need to
+    /// juggle two iterators that each borrow it. The second name is
+    /// regenerated until it differs from the first; if that fails
+    /// [`DEFAULT_RETRIES`] times – as it will when there are fewer than two
+    /// possible names – the iterator ends.
+    ///
+    pub fn iter_pairs<RNG>(
+        &self,
+        rng: &'a mut RNG,
+        words: u8,
+        separator: &str,
+    ) -> impl Iterator<Item = (String, String)> + '_
+    where
+        RNG: rand::Rng,
+    {
+        let separator = separator.to_string();
+        core::iter::from_fn(move || {
+            let first = self.generate(rng, words, &separator);
+            let second =
+                self.generate_matching(rng, words, &separator, DEFAULT_RETRIES, |name| {
+                    name != first
+                })?;
+            Some((first, second))
+        })
+    }
+
+    /// Iterator yielding petnames that are probably unique.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut rng = rand::rng();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let petnames = petname::Petnames::default();
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// let mut iter = petnames.iter_probably_unique(&mut rng, 4, "_", 1_000_000, 0.001);
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// println!("name: {}", iter.next().unwrap());
+    /// ```
+    ///
+    /// Names already yielded are remembered in a [Bloom filter][bloom] sized
+    /// for `expected` names, which uses a few bits per name rather than
+    /// holding every name – or, like
+    /// [`iter_non_repeating`][`Petnames::iter_non_repeating`], every word list
+    /// – in memory.
+    ///
+    /// The guarantee is probabilistic, but only in one direction: a name is
+    /// **never** yielded twice, but a name not yet seen may be skipped,
+    /// mistaken for a duplicate, with a probability of roughly
+    /// `false_positive_rate`. That probability grows once more than `expected`
+    /// names have been yielded. The iterator ends after [`DEFAULT_RETRIES`]
+    /// consecutive skips, e.g. when the names are exhausted.
+    ///
+    /// Requires the `bloom` feature.
+    ///
+    /// [bloom]: https://en.wikipedia.org/wiki/Bloom_filter
+    #[cfg(feature = "bloom")]
+    pub fn iter_probably_unique<RNG>(
+        &self,
+        rng: &'a mut RNG,
+        words: u8,
+        separator: &str,
+        expected: usize,
+        false_positive_rate: f64,
+    ) -> impl Iterator<Item = String> + '_
+    where
+        RNG: rand::Rng,
+    {
+        let mut seen = bloom::Bloom::new(expected, false_positive_rate);
+        let separator = separator.to_string();
+        core::iter::from_fn(move || {
+            (0..DEFAULT_RETRIES)
+                .map(|_| self.generate(rng, words, &separator))
+                .find(|name| !seen.insert(name))
+        })
     }
 
     /// Iterator yielding unique – i.e. non-repeating – petnames.
@@ -253,7 +2986,7 @@ impl<'a> Petnames<'a> {
     ///
     /// ```rust
     /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
-    /// let mut rng = rand::thread_rng();
+    /// let mut rng = rand::rng();
     /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
     /// let petnames = petname::Petnames::default();
     /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
@@ -262,17 +2995,82 @@ impl<'a> Petnames<'a> {
     /// println!("name: {}", iter.next().unwrap());
     /// ```
     ///
+    /// # Notes
+    ///
+    /// All of the randomness comes from `rng`: each word list is shuffled in
+    /// turn – adverbs, adjectives, then names – before iteration begins. Given
+    /// an identically seeded `rng` and word lists in the same order, this will
+    /// yield exactly the same sequence of names. See
+    /// [`NonRepeatingNames::reshuffle`] to change the order part way through.
+    ///
     pub fn iter_non_repeating<RNG>(
         &'a self,
-        rng: &'a mut RNG,
+        rng: &mut RNG,
         words: u8,
         separator: &str,
-    ) -> impl Iterator<Item = String> + 'a
+    ) -> NonRepeatingNames<'a>
     where
         RNG: rand::Rng,
     {
         let lists: Vec<Words<'a>> = Lists::new(self, words).cloned().collect();
-        NamesProduct::shuffled(&lists, rng, separator)
+        NonRepeatingNames::shuffled(lists, rng, separator)
+    }
+
+    /// Iterator yielding reproducible petnames from `seed`, which owns this
+    /// `Petnames` and its random number generator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+    /// # {
+    /// let names = petname::Petnames::default().into_iter_owned(42, 2, "-");
+    /// let handle = std::thread::spawn(move || names.take(3).collect::<Vec<_>>());
+    /// assert_eq!(3, handle.join().unwrap().len());
+    /// # }
+    /// ```
+    ///
+    /// Unlike [`iter`][`Petnames::iter`], the iterator borrows nothing but the
+    /// words themselves, so from a `Petnames<'static>` – like the built-in
+    /// word lists – it is `'static` and `Send`, and can be moved to another
+    /// thread. It
+    /// yields the same names as
+    /// [`iter_seeded_offset`][`Petnames::iter_seeded_offset`] with an offset
+    /// of 0.
+    ///
+    pub fn into_iter_owned(self, seed: u64, words: u8, separator: &str) -> OwnedNames<'a> {
+        OwnedNames {
+            petnames: self,
+            rng: SplitMix64(seed),
+            words,
+            separator: separator.to_string(),
+        }
+    }
+
+    /// Freeze this `Petnames`, precomputing its cardinality for every
+    /// possible number of words.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let petnames = petname::Petnames::init("a b", "c d e", "f g h i");
+    /// let frozen = petnames.freeze();
+    /// assert_eq!(24, frozen.cardinality(3));
+    /// ```
+    ///
+    /// The word lists of a [`FrozenPetnames`] cannot be modified, so the
+    /// cached cardinalities cannot go stale. Use [`FrozenPetnames::thaw`] to
+    /// get the `Petnames` back.
+    ///
+    pub fn freeze(self) -> FrozenPetnames<'a> {
+        let mut cardinalities = [0u128; 256];
+        for (words, cardinality) in cardinalities.iter_mut().enumerate() {
+            *cardinality = self.cardinality(words as u8);
+        }
+        FrozenPetnames {
+            petnames: self,
+            cardinalities,
+        }
     }
 }
 
@@ -283,16 +3081,526 @@ impl<'a> Default for Petnames<'a> {
     }
 }
 
+/// Add tagged words to the matching word lists.
+///
+/// # Examples
+///
+/// ```rust
+/// use petname::{Petnames, WordKind};
+/// let mut petnames = Petnames::init("happy", "", "");
+/// petnames.extend(vec![(WordKind::Adverb, "very"), (WordKind::Name, "cat")]);
+/// assert_eq!(Petnames::init("happy", "very", "cat"), petnames);
+/// ```
+///
+/// Words are appended as given – they are not split on whitespace – and no
+/// attempt is made to avoid duplicates.
+impl<'a> Extend<(WordKind, &'a str)> for Petnames<'a> {
+    fn extend<T: IntoIterator<Item = (WordKind, &'a str)>>(&mut self, iter: T) {
+        for (kind, word) in iter {
+            match kind {
+                WordKind::Adverb => self.adverbs.push(word),
+                WordKind::Adjective => self.adjectives.push(word),
+                WordKind::Name => self.names.push(word),
+            }
+        }
+    }
+}
+
+/// Construct a `Petnames` from a fuzzer's bytes.
+///
+/// Three strings are taken from the input – adjectives, adverbs, then names –
+/// each borrowed as by `<&str as Arbitrary>::arbitrary` and split on
+/// whitespace as by [`Petnames::init`]. Lastly, an [`AdverbPolicy`] is chosen.
+/// Any of the word lists may be empty.
+///
+/// Requires the `arbitrary` feature.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Petnames<'a> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let (adjectives, adverbs, names) = (u.arbitrary()?, u.arbitrary()?, u.arbitrary()?);
+        let adverb_policy = *u.choose(&[
+            AdverbPolicy::Linear,
+            AdverbPolicy::AtMostOne,
+            AdverbPolicy::None,
+        ])?;
+        Ok(Self::init(adjectives, adverbs, names).with_adverb_policy(adverb_policy))
+    }
+}
+
+/// Statistics over a sample of petnames; see [`Petnames::sample_stats`].
+///
+/// Lengths are in characters. For an empty sample, every field is zero.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SampleStats {
+    /// The number of names generated.
+    pub samples: usize,
+    /// The length of the shortest name.
+    pub min_len: usize,
+    /// The length of the longest name.
+    pub max_len: usize,
+    /// The mean length of the names.
+    pub mean_len: f64,
+    /// The number of different names.
+    pub unique: usize,
+}
+
+/// Error from [`Petnames::require_cardinality`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CardinalityError {
+    /// The number of words in each name.
+    pub words: u8,
+    /// The number of possible names of that many words.
+    pub cardinality: u128,
+    /// The number of possible names required.
+    pub minimum: u128,
+}
+
+impl core::fmt::Display for CardinalityError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "only {} possible names of {} words; at least {} required",
+            self.cardinality, self.words, self.minimum
+        )
+    }
+}
+
+/// A placeholder in a template that is not one of `{adjective}`, `{adverb}`,
+/// or `{name}`; see [`Petnames::check_template`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownPlaceholder(pub String);
+
+impl core::fmt::Display for UnknownPlaceholder {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "unknown placeholder {{{}}}", self.0)
+    }
+}
+
+/// Errors from constructing, loading, and filtering word lists.
+///
+/// This implements [`std::error::Error`] when the `std` feature is enabled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PetnameError {
+    /// A list of words of the given kind is empty; see
+    /// [`Petnames::try_init`].
+    EmptyList(WordKind),
+    /// No words begin with the given letter or, without a letter, the word
+    /// lists have no initial letters in common; see
+    /// [`Petnames::retain_alliterating`].
+    Alliteration(Option<char>),
+    /// Word lists could not be read: the path of each, and the kind of error
+    /// reading it; see [`Dictionary::load_dir`].
+    ///
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    FileIo(Vec<(std::path::PathBuf, std::io::ErrorKind)>),
+}
+
+impl core::fmt::Display for PetnameError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            PetnameError::EmptyList(kind) => {
+                let category = match kind {
+                    WordKind::Adverb => "adverbs",
+                    WordKind::Adjective => "adjectives",
+                    WordKind::Name => "names",
+                };
+                write!(f, "no {} given", category)
+            }
+            PetnameError::Alliteration(Some(letter)) => {
+                write!(f, "cannot alliterate: no words begin with {:?}", letter)
+            }
+            PetnameError::Alliteration(None) => write!(
+                f,
+                "cannot alliterate: word lists have no initial letters in common"
+            ),
+            #[cfg(feature = "std")]
+            PetnameError::FileIo(ref errors) => {
+                write!(f, "cannot read word lists:")?;
+                for (path, kind) in errors {
+                    write!(f, "\n  {}: {}", path.display(), kind)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PetnameError {}
+
+/// The size of the built-in word lists; see [`Petnames::with_complexity`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Complexity {
+    /// Short, common words; see [`Petnames::small`].
+    #[default]
+    Small,
+    /// See [`Petnames::medium`].
+    Medium,
+    /// Longer, rarer words; see [`Petnames::large`].
+    Large,
+}
+
+impl Complexity {
+    /// Every complexity, from smallest to largest.
+    pub const ALL: [Complexity; 3] = [Complexity::Small, Complexity::Medium, Complexity::Large];
+
+    /// Iterate over every complexity, from smallest to largest; see
+    /// [`ALL`][`Complexity::ALL`].
+    pub fn all() -> impl Iterator<Item = Complexity> {
+        Self::ALL.iter().copied()
+    }
+}
+
+/// The kind of a word in a petname, i.e. the list it was chosen from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WordKind {
+    Adverb,
+    Adjective,
+    Name,
+}
+
+impl WordKind {
+    /// The kind of word for a [`Petnames::generate_template`] placeholder.
+    fn from_placeholder(placeholder: &str) -> Option<Self> {
+        match placeholder {
+            "adverb" => Some(Self::Adverb),
+            "adjective" => Some(Self::Adjective),
+            "name" => Some(Self::Name),
+            _ => None,
+        }
+    }
+}
+
+/// Capitalisation of the words in a petname.
+///
+/// Case is mapped with Unicode's default, locale-independent rules – those of
+/// [`char::to_uppercase`] and [`str::to_lowercase`] – so results are the same
+/// everywhere but may be wrong for some languages. In Turkish, for example,
+/// "I" lower-cases to "i" rather than dotless "ı", and "i" upper-cases to "I"
+/// rather than dotted "İ". To apply a locale's rules, case the words from
+/// [`Petnames::generate_tagged`] yourself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Casing {
+    /// All words in lower case, e.g. "happy-little-cat".
+    Lower,
+    /// Every word capitalised, e.g. "Happy-Little-Cat".
+    Title,
+    /// Only the first word capitalised, e.g. "Happy-little-cat".
+    Sentence,
+    /// Only the name / noun capitalised, e.g. "happy-little-Cat".
+    NounOnly,
+}
+
+impl Casing {
+    /// Append `word`, which is at `index` in the name and of the given
+    /// `kind`, to `name`.
+    fn apply(self, index: usize, kind: WordKind, word: &str, name: &mut String) {
+        match self {
+            Self::Title => capitalize(word, name),
+            Self::Sentence if index == 0 => capitalize(word, name),
+            Self::NounOnly if kind == WordKind::Name => capitalize(word, name),
+            Self::Lower | Self::Sentence | Self::NounOnly => name.push_str(&word.to_lowercase()),
+        }
+    }
+}
+
+/// A piece of a template for [`Petnames::generate_template`].
+enum TemplatePart<'t> {
+    /// Literal text, with escaped braces already unescaped.
+    Text(&'t str),
+    /// The text between unescaped braces, e.g. "name" in "{name}".
+    Placeholder(&'t str),
+}
+
+/// Split `template` into literal text and placeholders. A brace that does not
+/// start or end a placeholder, and is not escaped by doubling, is text.
+fn template_parts(template: &str) -> impl Iterator<Item = TemplatePart<'_>> {
+    let mut rest = template;
+    core::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let part = if let Some(after) = rest.strip_prefix("{{") {
+            rest = after;
+            TemplatePart::Text("{")
+        } else if let Some(after) = rest.strip_prefix("}}") {
+            rest = after;
+            TemplatePart::Text("}")
+        } else if let Some((placeholder, after)) = rest
+            .strip_prefix('{')
+            .and_then(|after| after.split_once('}'))
+            .filter(|(placeholder, _)| !placeholder.contains('{'))
+        {
+            rest = after;
+            TemplatePart::Placeholder(placeholder)
+        } else {
+            // Text runs up to the next brace, but always includes at least
+            // one character so that a stray brace is consumed.
+            let end = rest
+                .char_indices()
+                .skip(1)
+                .find(|&(_, c)| c == '{' || c == '}')
+                .map_or(rest.len(), |(index, _)| index);
+            let (text, after) = rest.split_at(end);
+            rest = after;
+            TemplatePart::Text(text)
+        };
+        Some(part)
+    })
+}
+
+// The length of the longest name made from one word of each of `lists`,
+// skipping empty lists. This saturates rather than wrapping.
+fn max_name_length<'a, 'b: 'a>(
+    lists: impl Iterator<Item = &'a [&'b str]>,
+    separator: &str,
+) -> usize {
+    let (words, length) = lists
+        // The length of the longest possible word in each word list.
+        .filter_map(|words| words.iter().map(|word| word.len()).max())
+        .fold((0usize, 0usize), |(words, sum), len| {
+            (words + 1, sum.saturating_add(len))
+        });
+    // The total length of all separators.
+    length.saturating_add(separator.len().saturating_mul(words.saturating_sub(1)))
+}
+
+/// Keep only words in `list` of between `min` and `max` characters, inclusive.
+fn retain_len(list: &mut Words, min: usize, max: usize) {
+    list.retain(|word| (min..=max).contains(&word.chars().count()));
+}
+
+/// Map `index`, which must be less than `n`, to its place in a permutation of
+/// `0..n` chosen by `seed`.
+///
+/// This is a balanced Feistel network over the smallest even number of bits
+/// that can count to `n`, keyed by `seed`. Results outside `0..n` are fed
+/// back in – "cycle walking" – until one lands inside. Since the network
+/// covers fewer than `4 * n` values, that takes a few rounds at most, on
+/// average.
+fn permute(seed: u64, index: u128, n: u128) -> u128 {
+    use rand::RngCore;
+    let bits = (u128::BITS - (n - 1).leading_zeros()).max(2);
+    let half = bits.div_ceil(2);
+    let mask = u128::MAX >> (u128::BITS - half);
+    let mut x = index;
+    loop {
+        let (mut left, mut right) = (x >> half, x & mask);
+        for round in 0..4 {
+            let key = SplitMix64(seed.wrapping_add(round)).next_u64();
+            let f = SplitMix64(key ^ right as u64).next_u64();
+            (left, right) = (right, left ^ (u128::from(f) & mask));
+        }
+        x = (left << half) | right;
+        if x < n {
+            return x;
+        }
+    }
+}
+
+/// Append `word` to `name` with its first character in upper case and the
+/// rest in lower case. Some characters, like "ß", become more than one
+/// character when upper-cased.
+fn capitalize(word: &str, name: &mut String) {
+    let mut chars = word.chars();
+    if let Some(first) = chars.next() {
+        name.extend(first.to_uppercase());
+        name.push_str(&chars.as_str().to_lowercase());
+    }
+}
+
+/// Choose a stem uniformly from `list`, then a word uniformly from those that
+/// share that stem.
+fn choose_by_stem<'a, RNG>(list: &[&'a str], stem: usize, rng: &mut RNG) -> Option<&'a str>
+where
+    RNG: rand::Rng,
+{
+    let mut stems: BTreeMap<&str, Vec<&'a str>> = BTreeMap::new();
+    for word in list {
+        let key = word
+            .char_indices()
+            .nth(stem)
+            .map_or(*word, |(end, _)| &word[..end]);
+        stems.entry(key).or_default().push(word);
+    }
+    let (_, words) = stems.iter().choose(rng)?;
+    words.choose(rng).copied()
+}
+
+/// Is this a control or zero-width character? See `Petnames::retain_printable`.
+fn is_unprintable(c: char) -> bool {
+    c.is_control()
+        || matches!(
+            c,
+            '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{2060}' | '\u{FEFF}'
+        )
+}
+
+/// 64-bit FNV-1a hash; see `Petnames::fingerprint`.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// The [SplitMix64][splitmix] generator; see `Petnames::name_for`. This is
+/// not cryptographically secure, but it is small, fast, and – unlike the
+/// generators in `rand` – guaranteed never to change.
+///
+/// [splitmix]: https://prng.di.unimi.it/splitmix64.c
+#[derive(Clone, Debug)]
+struct SplitMix64(u64);
+
+impl rand::RngCore for SplitMix64 {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        rand::rand_core::impls::fill_bytes_via_next(self, dst)
+    }
+}
+
+/// An immutable `Petnames` with precomputed cardinalities.
+///
+/// This dereferences to [`Petnames`] so that all of the generation methods
+/// are available, but [`cardinality`][`FrozenPetnames::cardinality`] is a
+/// lookup rather than a calculation.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FrozenPetnames<'a> {
+    petnames: Petnames<'a>,
+    cardinalities: [u128; 256],
+}
+
+impl<'a> FrozenPetnames<'a> {
+    /// The cardinality of the frozen `Petnames`; see `Petnames::cardinality`.
+    pub fn cardinality(&self, words: u8) -> u128 {
+        self.cardinalities[words as usize]
+    }
+
+    /// Unfreeze, returning the original `Petnames`.
+    pub fn thaw(self) -> Petnames<'a> {
+        self.petnames
+    }
+}
+
+impl<'a> core::ops::Deref for FrozenPetnames<'a> {
+    type Target = Petnames<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.petnames
+    }
+}
+
+/// Several `Petnames`, e.g. of different complexities, mixed by weight.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+/// let mut rng = rand::rng();
+/// # #[cfg(feature = "default_dictionary")]
+/// let mixed = petname::MixedPetnames::new()
+///     .with(petname::Petnames::small(), 7)
+///     .with(petname::Petnames::large(), 3);
+/// # #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+/// mixed.generate(&mut rng, 2, "-");
+/// ```
+///
+/// Each name comes entirely from one `Petnames`, picked at random in
+/// proportion to its weight, so above, about 70% of names are small and 30%
+/// large. A weight of zero excludes a `Petnames`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MixedPetnames<'a> {
+    components: Vec<(Petnames<'a>, u32)>,
+}
+
+impl<'a> MixedPetnames<'a> {
+    /// Constructs a new, empty, `MixedPetnames`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `petnames` to the mix with the given `weight`.
+    pub fn with(mut self, petnames: Petnames<'a>, weight: u32) -> Self {
+        self.push(petnames, weight);
+        self
+    }
+
+    /// Add `petnames` to the mix with the given `weight`.
+    pub fn push(&mut self, petnames: Petnames<'a>, weight: u32) {
+        self.components.push((petnames, weight));
+    }
+
+    /// Calculate the cardinality of the mix.
+    ///
+    /// The possible names are the union of those of each `Petnames` with a
+    /// non-zero weight; the weights affect only how often each is used. This
+    /// sums the cardinalities, so a name possible in more than one
+    /// `Petnames` is counted more than once. Like
+    /// [`Petnames::cardinality`], this can saturate.
+    pub fn cardinality(&self, words: u8) -> u128 {
+        self.components
+            .iter()
+            .filter(|(_, weight)| *weight > 0)
+            .map(|(petnames, _)| petnames.cardinality(words))
+            .fold(0u128, u128::saturating_add)
+    }
+
+    /// Generate a new petname from one `Petnames`, picked by weight; see
+    /// [`Petnames::generate`].
+    ///
+    /// If there are no `Petnames`, or all weights are zero, this returns an
+    /// empty string.
+    pub fn generate<RNG, SEP>(&self, rng: &mut RNG, words: u8, separator: SEP) -> String
+    where
+        RNG: rand::Rng,
+        SEP: SeparatorStrategy,
+    {
+        match self.components.choose_weighted(rng, |(_, weight)| *weight) {
+            Ok((petnames, _)) => petnames.generate(rng, words, separator),
+            Err(_) => String::new(),
+        }
+    }
+}
+
 /// Iterator over a `Petnames`' word lists.
 ///
 /// This yields the appropriate lists from which to select a word when
 /// constructing a petname of `n` words. For example, if you want 3 words in
-/// your petname, this will first yield the adverbs word list, then adjectives,
-/// then names.
+/// your petname, this will – with the default [`AdverbPolicy::Linear`] – first
+/// yield the adverbs word list, then adjectives, then names.
 #[derive(Debug, PartialEq)]
 enum Lists<'a> {
-    Adverb(&'a Petnames<'a>, u8),
-    Adjective(&'a Petnames<'a>),
+    /// An adverb, then the given number of further adverbs and adjectives.
+    Adverb(&'a Petnames<'a>, u8, u8),
+    /// An adjective, then the given number of further adjectives.
+    Adjective(&'a Petnames<'a>, u8),
     Name(&'a Petnames<'a>),
     Done,
 }
@@ -302,16 +3610,47 @@ impl<'a> Lists<'a> {
         match words {
             0 => Self::Done,
             1 => Self::Name(names),
-            2 => Self::Adjective(names),
-            n => Self::Adverb(names, n - 3),
+            n => {
+                // There's always at least one adjective.
+                let adverbs = match names.adverb_policy {
+                    AdverbPolicy::Linear => n - 2,
+                    AdverbPolicy::AtMostOne => (n - 2).min(1),
+                    AdverbPolicy::None => 0,
+                };
+                let adjectives = n - 1 - adverbs;
+                match adverbs {
+                    0 => Self::Adjective(names, adjectives - 1),
+                    _ => Self::Adverb(names, adverbs - 1, adjectives),
+                }
+            }
+        }
+    }
+
+    fn kind(&self) -> Option<WordKind> {
+        match self {
+            Self::Adverb(_, _, _) => Some(WordKind::Adverb),
+            Self::Adjective(_, _) => Some(WordKind::Adjective),
+            Self::Name(_) => Some(WordKind::Name),
+            Self::Done => None,
         }
     }
 
+    /// Pair each list with the kind of word it contains.
+    fn tagged(mut self) -> impl Iterator<Item = (WordKind, &'a Words<'a>)> {
+        core::iter::from_fn(move || {
+            let kind = self.kind()?;
+            self.next().map(|list| (kind, list))
+        })
+    }
+
     fn advance(&mut self) {
         *self = match self {
-            Self::Adverb(names, 0) => Self::Adjective(names),
-            Self::Adverb(names, remaining) => Self::Adverb(names, *remaining - 1),
-            Self::Adjective(names) => Self::Name(names),
+            Self::Adverb(names, 0, adjectives) => Self::Adjective(names, *adjectives - 1),
+            Self::Adverb(names, adverbs, adjectives) => {
+                Self::Adverb(names, *adverbs - 1, *adjectives)
+            }
+            Self::Adjective(names, 0) => Self::Name(names),
+            Self::Adjective(names, adjectives) => Self::Adjective(names, *adjectives - 1),
             Self::Name(_) | Self::Done => Self::Done,
         }
     }
@@ -322,8 +3661,8 @@ impl<'a> Iterator for Lists<'a> {
 
     fn next(&mut self) -> Option<Self::Item> {
         let result = match self {
-            Self::Adverb(names, _) => Some(&names.adverbs),
-            Self::Adjective(names) => Some(&names.adjectives),
+            Self::Adverb(names, _, _) => Some(&names.adverbs),
+            Self::Adjective(names, _) => Some(&names.adjectives),
             Self::Name(names) => Some(&names.names),
             Self::Done => None,
         };
@@ -335,8 +3674,8 @@ impl<'a> Iterator for Lists<'a> {
 
     fn size_hint(&self) -> (usize, Option<usize>) {
         let remains = match self {
-            Self::Adverb(_, n) => (n + 3) as usize,
-            Self::Adjective(_) => 2,
+            Self::Adverb(_, adverbs, adjectives) => *adverbs as usize + *adjectives as usize + 2,
+            Self::Adjective(_, adjectives) => *adjectives as usize + 2,
             Self::Name(_) => 1,
             Self::Done => 0,
         };
@@ -376,118 +3715,172 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         Some(
             self.petnames
-                .generate(self.rng, self.words, &self.separator),
+                .generate(self.rng, self.words, self.separator.as_str()),
+        )
+    }
+}
+
+/// Iterator yielding petnames that owns its word lists and random number
+/// generator; see [`Petnames::into_iter_owned`].
+#[derive(Clone, Debug)]
+pub struct OwnedNames<'a> {
+    petnames: Petnames<'a>,
+    rng: SplitMix64,
+    words: u8,
+    separator: String,
+}
+
+impl<'a> OwnedNames<'a> {
+    /// Calculate the cardinality of this iterator; see `Petnames::cardinality`.
+    pub fn cardinality(&self) -> u128 {
+        self.petnames.cardinality(self.words)
+    }
+}
+
+impl<'a> Iterator for OwnedNames<'a> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(
+            self.petnames
+                .generate(&mut self.rng, self.words, self.separator.as_str()),
         )
     }
 }
 
-/// Iterator yielding petnames from the product of given word lists.
+/// Iterator yielding unique – i.e. non-repeating – petnames; see
+/// [`Petnames::iter_non_repeating`].
 ///
-/// This can be used to ensure that only unique names are produced.
-struct NamesProduct<'a, ITERATOR>
-where
-    ITERATOR: Iterator<Item = Option<&'a str>>,
-{
-    iters: Vec<(ITERATOR, Option<&'a str>)>,
+/// This walks the product of the word lists, each shuffled, like an odometer
+/// in which the leftmost list turns most rapidly.
+#[derive(Clone, Debug)]
+pub struct NonRepeatingNames<'a> {
+    lists: Vec<Words<'a>>,
+    // The position in each list of the word in the next name to be yielded;
+    // the leftmost is the least significant digit. This is `None` once every
+    // name has been yielded.
+    positions: Option<Vec<usize>>,
     separator: String,
     capacity: usize,
 }
 
-impl<'a> NamesProduct<'a, core::iter::Cycle<alloc::vec::IntoIter<Option<&'a str>>>> {
-    /// Shuffles each of the given `lists` with `rng`, then cycles through the
-    /// product of the lists, joining with `separator`. The leftmost list will
-    /// cycle most rapidly.
-    fn shuffled<RNG>(lists: &[Words<'a>], rng: &'a mut RNG, separator: &str) -> Self
+impl<'a> NonRepeatingNames<'a> {
+    /// Shuffles each of the given `lists` with `rng`, then walks the product
+    /// of the lists, joining with `separator`.
+    fn shuffled<RNG>(lists: Vec<Words<'a>>, rng: &mut RNG, separator: &str) -> Self
     where
         RNG: rand::Rng,
     {
-        NamesProduct {
-            iters: lists
-                .iter()
-                .map(|words| {
-                    let mut list: Vec<Option<&'a str>> =
-                        Vec::with_capacity(words.len().saturating_add(1));
-                    list.extend(words.iter().map(|word| Some(*word)));
-                    list.shuffle(rng); // Could be expensive.
-                    list.push(None); // Cycle marker.
-                    (list.into_iter().cycle(), None)
-                })
-                .collect(),
+        let mut lists = lists;
+        for list in lists.iter_mut() {
+            list.shuffle(rng); // Could be expensive.
+        }
+        // With an empty list there are no names at all. With no lists there is
+        // exactly one name – the empty name – as in `cardinality(0)`.
+        let positions = if lists.iter().any(|list| list.is_empty()) {
+            None
+        } else {
+            Some(alloc::vec![0; lists.len()])
+        };
+        NonRepeatingNames {
+            capacity: Self::capacity(&lists, separator),
+            lists,
+            positions,
             separator: separator.to_string(),
-            capacity: Self::capacity(lists, separator),
         }
     }
 
     fn capacity(lists: &[Words<'a>], separator: &str) -> usize {
-        (
-            // Sum of the length of the longest possible word in each word list.
-            lists
-                .iter()
-                .filter_map(|words| words.iter().map(|word| word.len()).max())
-                .fold(0usize, |sum, len| sum.saturating_add(len))
-            // The total length of all separators. Careful not to wrap usize.
-            + (separator.len().saturating_mul(lists.len().saturating_sub(1)))
-        )
-        // Things run _much_ quicker when the capacity is a power of 2. Memory
-        // alignment? If so it may be enough to align at, say, 8 bytes, but this
-        // works for now.
-        .checked_next_power_of_two()
-        // In case there are no lists, or they're all empty... or we have
-        // calculated that we need more than usize::MAX capacity.
-        .unwrap_or(0)
+        max_name_length(lists.iter().map(|list| &list[..]), separator)
+            // Things run _much_ quicker when the capacity is a power of 2. Memory
+            // alignment? If so it may be enough to align at, say, 8 bytes, but this
+            // works for now.
+            .checked_next_power_of_two()
+            // In case there are no lists, or they're all empty... or we have
+            // calculated that we need more than usize::MAX capacity.
+            .unwrap_or(0)
+    }
+
+    /// Shuffle again the order in which the remaining names will be yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std_rng")]
+    /// let mut rng = rand::rng();
+    /// let petnames = petname::Petnames::init("a b c", "d e", "f g h");
+    /// # #[cfg(feature = "std_rng")]
+    /// let mut names = petnames.iter_non_repeating(&mut rng, 2, "-");
+    /// # #[cfg(feature = "std_rng")]
+    /// let mut seen: Vec<String> = names.by_ref().take(4).collect();
+    /// # #[cfg(feature = "std_rng")]
+    /// names.reshuffle(&mut rng);
+    /// # #[cfg(feature = "std_rng")]
+    /// seen.extend(names);
+    /// # #[cfg(feature = "std_rng")]
+    /// assert_eq!(9, seen.len());
+    /// ```
+    ///
+    /// Names already yielded will still never be yielded again, and every
+    /// name not yet yielded still will be.
+    ///
+    /// # Notes
+    ///
+    /// Names are yielded in order of their positions, so the names not yet
+    /// yielded are those at or after the current positions. For the rightmost
+    /// – most significant – list, every word after its current position
+    /// starts a run of names that are all still to come, and every word
+    /// before it a run of names that have all been yielded; those words can
+    /// be shuffled among themselves, on each side of the current word, which
+    /// stays put. The same then holds for the next list, within the run of
+    /// the current word, and so on. Before any name has been yielded, every
+    /// list can be shuffled entirely.
+    ///
+    pub fn reshuffle<RNG>(&mut self, rng: &mut RNG)
+    where
+        RNG: rand::Rng,
+    {
+        if let Some(positions) = &self.positions {
+            let fresh = positions.iter().all(|&position| position == 0);
+            for (list, &position) in self.lists.iter_mut().zip(positions) {
+                if fresh {
+                    list.shuffle(rng);
+                } else {
+                    let (before, rest) = list.split_at_mut(position);
+                    before.shuffle(rng);
+                    rest[1..].shuffle(rng);
+                }
+            }
+        }
     }
 }
 
-impl<'a, ITERATOR> Iterator for NamesProduct<'a, ITERATOR>
-where
-    ITERATOR: Iterator<Item = Option<&'a str>>,
-{
+impl<'a> Iterator for NonRepeatingNames<'a> {
     type Item = String;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let mut bump = true; // Request advance of next iterator.
-        for (iter, word) in self.iters.iter_mut() {
-            if bump || word.is_none() {
-                match iter.next() {
-                    None => {
-                        // This shouldn't happen because we expect the iterators
-                        // to cycle. However, if it does, we're definitely done.
-                        return None;
-                    }
-                    Some(None) => {
-                        // This is the cycle end marker. We want to get another
-                        // new word from this iterator, and advance the *next*
-                        // iterator too.
-                        match iter.next() {
-                            None => return None,
-                            Some(None) => return None,
-                            Some(s) => *word = s,
-                        }
-                        bump = true
-                    }
-                    Some(s) => {
-                        // We have a new word from this iterator, so we do not
-                        // yet need to advance the next iterator.
-                        *word = s;
-                        bump = false
-                    }
-                }
+        let positions = self.positions.as_mut()?;
+        let mut name = String::with_capacity(self.capacity);
+        for (index, (list, &position)) in self.lists.iter().zip(positions.iter()).enumerate() {
+            if index > 0 {
+                name.push_str(&self.separator);
             }
+            name.push_str(list[position]);
         }
-        if bump {
-            // We reached the end of the last iterator, hence we're done.
-            None
-        } else {
-            // We may be able to construct a word!
-            self.iters.iter().fold(
-                Some(String::with_capacity(self.capacity)),
-                |acc, (_, w)| match (acc, *w) {
-                    (Some(s), Some(w)) if s.is_empty() => Some(s + w),
-                    (Some(s), Some(w)) => Some(s + &self.separator + w),
-                    _ => None,
-                },
-            )
+        // Advance the odometer; when the last list rolls over, we're done.
+        let mut rolled_over = true;
+        for (list, position) in self.lists.iter().zip(positions.iter_mut()) {
+            *position += 1;
+            if *position < list.len() {
+                rolled_over = false;
+                break;
+            }
+            *position = 0;
         }
+        if rolled_over {
+            self.positions = None;
+        }
+        Some(name)
     }
 }
 
@@ -499,11 +3892,11 @@ mod tests {
     fn lists_sequences_adverbs_adjectives_then_names() {
         let petnames = super::Petnames::init("adjective", "adverb", "name");
         let mut lists = super::Lists::new(&petnames, 4);
-        assert_eq!(super::Lists::Adverb(&petnames, 1), lists);
+        assert_eq!(super::Lists::Adverb(&petnames, 1, 1), lists);
         assert_eq!(Some(&vec!["adverb"]), lists.next());
-        assert_eq!(super::Lists::Adverb(&petnames, 0), lists);
+        assert_eq!(super::Lists::Adverb(&petnames, 0, 1), lists);
         assert_eq!(Some(&vec!["adverb"]), lists.next());
-        assert_eq!(super::Lists::Adjective(&petnames), lists);
+        assert_eq!(super::Lists::Adjective(&petnames, 0), lists);
         assert_eq!(Some(&vec!["adjective"]), lists.next());
         assert_eq!(super::Lists::Name(&petnames), lists);
         assert_eq!(Some(&vec!["name"]), lists.next());
@@ -511,6 +3904,26 @@ mod tests {
         assert_eq!(None, lists.next());
     }
 
+    #[test]
+    fn fnv1a_matches_reference_values() {
+        use core::hash::Hasher;
+        let mut hash = super::Fnv1a::new();
+        assert_eq!(0xcbf2_9ce4_8422_2325, hash.finish());
+        hash.write(b"a");
+        assert_eq!(0xaf63_dc4c_8601_ec8c, hash.finish());
+    }
+
+    #[test]
+    fn lists_size_hint_with_at_most_one_adverb() {
+        let petnames = super::Petnames::init("adjective", "adverb", "name")
+            .with_adverb_policy(super::AdverbPolicy::AtMostOne);
+        let mut lists = super::Lists::new(&petnames, 5);
+        for remains in (0..=5).rev() {
+            assert_eq!((remains, Some(remains)), lists.size_hint());
+            lists.next();
+        }
+    }
+
     #[test]
     fn lists_size_hint() {
         let petnames = super::Petnames::init("adjective", "adverb", "name");
@@ -1,16 +1,21 @@
 mod cli;
 
 use cli::Cli;
-use petname::Petnames;
+use petname::{CardinalityError, Dictionary, PetnameError, Petnames, UnknownPlaceholder};
 
-use std::collections::HashSet;
+use std::collections::BTreeMap;
 use std::fmt;
 use std::fs;
+use std::hint;
 use std::io;
 use std::path;
 use std::process;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time;
 
-use rand::seq::IteratorRandom;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use structopt::StructOpt;
 
 fn main() {
@@ -28,9 +33,16 @@ fn main() {
 
 enum Error {
     Io(io::Error),
-    FileIo(path::PathBuf, io::Error),
+    Petname(PetnameError),
     Cardinality(String),
-    Alliteration(String),
+    MinCardinality(CardinalityError),
+    Retries(usize, String),
+    Separator(String),
+    Template(UnknownPlaceholder),
+    Words(String),
+    Output(path::PathBuf, io::Error),
+    Key(String),
+    Seed(String),
     Disconnected,
 }
 
@@ -38,9 +50,20 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             Error::Io(ref e) => write!(f, "{}", e),
-            Error::FileIo(ref path, ref e) => write!(f, "{}: {}", e, path.display()),
+            Error::Petname(ref error) => write!(f, "{}", error),
             Error::Cardinality(ref message) => write!(f, "cardinality is zero: {}", message),
-            Error::Alliteration(ref message) => write!(f, "cannot alliterate: {}", message),
+            Error::MinCardinality(ref error) => write!(f, "cardinality is too low: {}", error),
+            Error::Retries(retries, ref message) => {
+                write!(f, "gave up after {} attempts: {}", retries, message)
+            }
+            Error::Separator(ref message) => write!(f, "invalid separator: {}", message),
+            Error::Template(ref error) => write!(f, "invalid template: {}", error),
+            Error::Words(ref message) => write!(f, "invalid word count: {}", message),
+            Error::Output(ref path, ref e) => {
+                write!(f, "cannot write to {}: {}", path.display(), e)
+            }
+            Error::Key(ref message) => write!(f, "cannot use key: {}", message),
+            Error::Seed(ref message) => write!(f, "cannot seed: {}", message),
             Error::Disconnected => write!(f, "caller disconnected / stopped reading"),
         }
     }
@@ -52,80 +75,286 @@ impl From<io::Error> for Error {
     }
 }
 
+impl From<PetnameError> for Error {
+    fn from(error: PetnameError) -> Self {
+        Error::Petname(error)
+    }
+}
+
 fn run(cli: Cli) -> Result<(), Error> {
+    // Upstream defaults to medium words; we default to small.
+    let complexity = cli.complexity.unwrap_or(if cli.compat { 1 } else { 0 });
+
+    // Check the template, if given, before doing any work.
+    if let Some(template) = &cli.template {
+        Petnames::check_template(template).map_err(Error::Template)?;
+    }
+
+    // Describe the dictionary now, before its paths are consumed.
+    let dictionary = match (&cli.directory, &cli.flat) {
+        (Some(dirname), _) => format!("directory {}", dirname.display()),
+        (None, Some(filename)) => format!("flat {}", filename.display()),
+        (None, None) => format!("complexity {}", complexity),
+    };
+
     // Load custom word lists, if specified.
-    let words = match cli.directory {
-        Some(dirname) => Words::load(dirname)?,
-        None => Words::Builtin,
+    let custom = match (cli.directory, cli.flat) {
+        (Some(dirname), _) => Some(Dictionary::load_dir(dirname)?),
+        (None, Some(filename)) => Some(Dictionary::load_file(filename)?),
+        (None, None) => None,
     };
 
     // Select the appropriate word list.
-    let mut petnames = match words {
-        Words::Custom(ref adjectives, ref adverbs, ref names) => {
-            Petnames::init(adjectives, adverbs, names)
-        }
-        Words::Builtin => match cli.complexity {
-            0 => Petnames::small(),
+    let mut petnames = match custom {
+        Some(ref dictionary) => dictionary.petnames(),
+        None => match complexity {
             1 => Petnames::medium(),
             2 => Petnames::large(),
             _ => Petnames::small(),
         },
     };
 
-    // If requested, limit the number of letters.
-    let letters = cli.letters;
-    if letters != 0 {
-        petnames.retain(|s| s.len() <= letters);
+    petnames.adverb_policy = cli.adverb_policy;
+
+    // With a range of word counts, the fewest words give the fewest names, so
+    // check cardinality against that.
+    let min_words = cli.words.min;
+
+    // Split the separator into one for each gap, if there's more than one.
+    let separators = parse_separators(&cli.separator, &cli.separators);
+
+    // Warn – or, with --strict, fail – if names could be ambiguous: if custom
+    // words contain a separator, or if names must be unique but an empty
+    // separator could make different names look the same, e.g. "ab" + "c"
+    // and "a" + "bc".
+    let mut ambiguities = Vec::new();
+    if custom.is_some() {
+        for separator in &separators {
+            if !separator.is_empty() && !petnames.separator_is_safe(separator) {
+                ambiguities.push(format!(
+                    "some words contain the separator {:?}; names may be ambiguous",
+                    separator
+                ));
+            }
+        }
+    }
+    if cli.non_repeating && separators.iter().any(String::is_empty) {
+        ambiguities.push(
+            "the separator is empty; non-repeating names may still look the same".to_string(),
+        );
+    }
+    for ambiguity in ambiguities {
+        if cli.strict {
+            return Err(Error::Separator(ambiguity));
+        }
+        eprintln!("Warning: {}.", ambiguity);
     }
 
-    // Check cardinality.
-    if petnames.cardinality(cli.words) == 0 {
-        return Err(Error::Cardinality(
-            "no petnames to choose from; try relaxing constraints".to_string(),
+    // Warn that --letters=0 is deprecated.
+    if cli.letters == Some(0) {
+        eprintln!(concat!(
+            "Warning: specifying --letters=0 to allow words of any length is ",
+            "deprecated and its behaviour will change in a future version; ",
+            "specify --no-letter-limit instead.",
         ));
     }
 
-    // We're going to need a source of randomness.
-    let mut rng = rand::thread_rng();
+    // If requested, limit the number of letters. TODO: Stop treating 0 as
+    // unlimited once --letters=0 is no longer deprecated.
+    let letters = if cli.no_letter_limit {
+        None
+    } else {
+        cli.letters
+    };
+    if let Some(letters) = letters.filter(|&letters| letters != 0) {
+        petnames.retain(|s| s.len() <= letters);
+    }
+
+    // If requested, limit the number of letters in each kind of word.
+    if let Some(range) = cli.adj_letters {
+        petnames.retain_adjectives_len(range.min, range.max);
+    }
+    if let Some(range) = cli.adv_letters {
+        petnames.retain_adverbs_len(range.min, range.max);
+    }
+    if let Some(range) = cli.name_letters {
+        petnames.retain_names_len(range.min, range.max);
+    }
+
+    // If requested, limit the initial letters.
+    if let Some(initials) = &cli.initials {
+        petnames.retain_initials_ignore_case(&initials.chars().collect::<Vec<_>>());
+    }
+
+    // If requested, drop words with invisible characters.
+    if cli.printable_only {
+        petnames.retain_printable();
+    }
+
+    // If requested, drop words that aren't plain ASCII.
+    if cli.ascii_only {
+        petnames.retain_ascii();
+    }
+
+    // If requested, drop profanity.
+    #[cfg(feature = "blocklist")]
+    if cli.safe {
+        petnames.retain_safe();
+    }
 
-    // Handle alliteration, either by eliminating a specified
-    // character, or using a random one.
-    let alliterate = cli.alliterate || cli.ubuntu || cli.alliterate_with.is_some();
-    if alliterate {
-        // We choose the first letter from the intersection of the
-        // first letters of each word list in `petnames`.
-        let firsts =
-            common_first_letters(&petnames.adjectives, &[&petnames.adverbs, &petnames.names]);
-        // if a specific character was requested for alliteration,
-        // attempt to use it.
+    // Report the effective settings, if requested.
+    if cli.dump_config {
+        let mut filters = Vec::new();
+        if let Some(letters) = letters.filter(|&letters| letters != 0) {
+            filters.push(format!("letters<={}", letters));
+        }
+        for (kind, range) in &[
+            ("adjective", cli.adj_letters),
+            ("adverb", cli.adv_letters),
+            ("name", cli.name_letters),
+        ] {
+            if let Some(range) = range {
+                filters.push(format!("{} letters {}", kind, range));
+            }
+        }
+        if let Some(initials) = &cli.initials {
+            filters.push(format!("initials {:?}", initials));
+        }
+        if cli.printable_only {
+            filters.push("printable-only".to_string());
+        }
+        if cli.ascii_only {
+            filters.push("ascii-only".to_string());
+        }
+        #[cfg(feature = "blocklist")]
+        if cli.safe {
+            filters.push("safe".to_string());
+        }
         if let Some(c) = cli.alliterate_with {
-            if firsts.contains(&c) {
-                petnames.retain(|s| s.starts_with(c));
+            filters.push(format!("alliterate-with {:?}", c));
+        } else if cli.alliterate || cli.ubuntu {
+            filters.push("alliterate".to_string());
+        }
+        eprintln!("config: words = {}", cli.words);
+        eprintln!("config: separators = {:?}", separators);
+        eprintln!("config: adverb-policy = {:?}", petnames.adverb_policy);
+        eprintln!("config: dictionary = {}", dictionary);
+        if filters.is_empty() {
+            eprintln!("config: filters = none");
+        } else {
+            eprintln!("config: filters = {}", filters.join(", "));
+        }
+        eprintln!(
+            "config: cardinality = {}{}",
+            petnames.cardinality(min_words),
+            if filters.iter().any(|f| f.starts_with("alliterate")) {
+                " (before alliteration)"
             } else {
-                return Err(Error::Alliteration(
-                    "no petnames begin with the choosen alliteration character".to_string(),
-                ));
+                ""
             }
-        } else {
-            // Otherwise choose the first letter at random; fails if
-            // there are no letters.
-            match firsts.iter().choose(&mut rng) {
-                Some(c) => petnames.retain(|s| s.starts_with(*c)),
-                None => {
-                    return Err(Error::Alliteration(
-                        "word lists have no initial letters in common".to_string(),
-                    ))
-                }
-            };
+        );
+    }
+
+    // Report on the word lists, if requested.
+    if cli.stats {
+        for (category, list) in &[
+            ("adjectives", &petnames.adjectives),
+            ("adverbs", &petnames.adverbs),
+            ("names", &petnames.names),
+        ] {
+            let counts = first_letter_counts(list)
+                .iter()
+                .map(|(c, n)| format!("{}={}", c, n))
+                .collect::<Vec<_>>()
+                .join(" ");
+            eprintln!("{}: {}", category, counts);
+        }
+    }
+
+    // Report on word lengths, if requested.
+    if cli.length_histogram {
+        for (length, count) in petnames.length_histogram() {
+            eprintln!("length {}: {}", length, count);
         }
     }
 
-    // Manage stdout.
-    let stdout = io::stdout();
-    let mut writer = io::BufWriter::new(stdout.lock());
+    // We're going to need a source of randomness. Seed it from --seed, else
+    // from $PETNAME_SEED, else at random.
+    let seed = match (cli.seed, std::env::var_os("PETNAME_SEED")) {
+        (Some(seed), _) => Some(seed),
+        (None, Some(seed)) => Some(
+            seed.to_str()
+                .and_then(|seed| seed.parse().ok())
+                .ok_or_else(|| Error::Seed(format!("PETNAME_SEED is not a number: {:?}", seed)))?,
+        ),
+        (None, None) => None,
+    };
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_rng(&mut rand::rng()),
+    };
+
+    // Handle alliteration, with the given letter or a random one.
+    if cli.alliterate || cli.ubuntu || cli.alliterate_with.is_some() {
+        petnames.retain_alliterating(&mut rng, cli.alliterate_with)?;
+    }
+
+    // List the words in a category, if requested, and do nothing else.
+    if let Some(category) = cli.list_words {
+        let list = match category.as_str() {
+            "adjectives" => &petnames.adjectives,
+            "adverbs" => &petnames.adverbs,
+            _ => &petnames.names,
+        };
+        let mut writer = open_output(cli.output.as_ref())?;
+        let words = list.iter().map(|word| Ok(word.to_string()));
+        let disconnect_ok = cli.output.is_none();
+        let printed = printer(
+            &mut writer,
+            words,
+            disconnect_ok,
+            None,
+            &AtomicBool::new(false),
+        );
+        return finish_output(printed, writer, cli.output);
+    }
+
+    // Check cardinality.
+    if petnames.cardinality(min_words) == 0 {
+        return Err(Error::Cardinality(
+            "no petnames to choose from; try relaxing constraints".to_string(),
+        ));
+    }
+    petnames
+        .require_cardinality(min_words, cli.min_cardinality)
+        .map_err(Error::MinCardinality)?;
+
+    // Generate the name for a key, if requested, and nothing else.
+    if let Some(key) = cli.key {
+        if cli.count.is_some_and(|count| count != 1) {
+            return Err(Error::Key(
+                "a key has only one name; --count must be 1".to_string(),
+            ));
+        }
+        if !cli.words.is_fixed() {
+            return Err(Error::Key(
+                "a key has only one name; --words must be a single number".to_string(),
+            ));
+        }
+        let name = petnames.name_for(key.as_str(), min_words, &separators[..]);
+        let mut writer = open_output(cli.output.as_ref())?;
+        let printed = printer(
+            &mut writer,
+            std::iter::once(Ok(name)),
+            cli.output.is_none(),
+            None,
+            &AtomicBool::new(false),
+        );
+        return finish_output(printed, writer, cli.output);
+    }
 
     // Warn that --count=0 is deprecated.
-    if cli.count == 0 {
+    if cli.count == Some(0) {
         eprintln!(concat!(
             "Warning: specifying --count=0 to continuously produce petnames is ",
             "deprecated and its behaviour will change in a future version; ",
@@ -134,81 +363,231 @@ fn run(cli: Cli) -> Result<(), Error> {
     }
 
     // Stream if count is 0. TODO: Only stream when --stream is specified.
-    let count = if cli.stream || cli.count == 0 {
-        None
-    } else {
-        Some(cli.count)
+    let stream = cli.stream || cli.count == Some(0);
+
+    // When streaming, --count is a ceiling; otherwise we want exactly --count
+    // names, defaulting to 1.
+    let count = match (stream, cli.count) {
+        (_, Some(0)) => None,
+        (true, count) => count,
+        (false, count) => Some(count.unwrap_or(1)),
     };
 
-    // Get an iterator for the names we want to print out.
-    if cli.non_repeating {
-        printer(
-            &mut writer,
-            petnames.iter_non_repeating(&mut rng, cli.words, &cli.separator),
-            count,
-        )
-    } else {
-        printer(
-            &mut writer,
-            petnames.iter(&mut rng, cli.words, &cli.separator),
-            count,
-        )
+    // A stream to stdout may be cut short by the reader, e.g. `head`; that's
+    // fine. A file should never be cut short.
+    let disconnect_ok = stream && cli.output.is_none();
+
+    // Stop streaming cleanly on Ctrl-C, if built with that feature, so that
+    // buffered names are flushed and we exit successfully.
+    let stop = Arc::new(AtomicBool::new(false));
+    #[cfg(feature = "ctrlc")]
+    if stream {
+        signal_hook::flag::register(signal_hook::consts::SIGINT, Arc::clone(&stop))?;
     }
-}
 
-fn printer<OUT, NAMES>(writer: &mut OUT, names: NAMES, count: Option<usize>) -> Result<(), Error>
-where
-    OUT: io::Write,
-    NAMES: Iterator<Item = String>,
-{
-    match count {
-        None => {
-            for name in names {
-                writeln!(writer, "{}", name).map_err(suppress_disconnect)?;
-            }
+    // Get an iterator for the names we want to print out.
+    let (words, separators) = (cli.words, &separators[..]);
+    let names: Box<dyn Iterator<Item = Result<String, Error>>> = if cli.non_repeating {
+        if !words.is_fixed() {
+            return Err(Error::Words(
+                "--non-repeating needs a single number of words".to_string(),
+            ));
         }
-        Some(n) => {
-            for name in names.take(n) {
-                writeln!(writer, "{}", name)?;
+        let separator = match separators {
+            [separator] => separator,
+            _ => {
+                return Err(Error::Separator(
+                    "--non-repeating needs a single separator".to_string(),
+                ))
             }
+        };
+        Box::new(
+            petnames
+                .iter_non_repeating(&mut rng, words.min, separator)
+                .map(Ok),
+        )
+    } else if let Some(length) = cli.length {
+        let (tolerance, retries) = (cli.tolerance, cli.retries);
+        Box::new(std::iter::repeat_with(move || {
+            let words = words.pick(&mut rng);
+            petnames
+                .generate_near_length(&mut rng, words, separators, length, tolerance, retries)
+                .ok_or_else(|| {
+                    Error::Retries(
+                        retries,
+                        format!("no petname of length {}±{} found", length, tolerance),
+                    )
+                })
+        }))
+    } else if let Some(template) = cli.template {
+        Box::new(std::iter::repeat_with(move || {
+            Ok(petnames.generate_template(&mut rng, &template))
+        }))
+    } else if cli.emoji {
+        Box::new(std::iter::repeat_with(move || {
+            let words = words.pick(&mut rng);
+            Ok(petnames.generate_with_emoji(&mut rng, words, separators, EMOJIS))
+        }))
+    } else {
+        Box::new(std::iter::repeat_with(move || {
+            let words = words.pick(&mut rng);
+            Ok(petnames.generate(&mut rng, words, separators))
+        }))
+    };
+
+    // Time name generation, if requested, without printing any names. This
+    // takes names from the same iterator as would be printed, so every option
+    // that shapes them is accounted for.
+    if let Some(n) = cli.benchmark {
+        let start = time::Instant::now();
+        let mut generated = 0;
+        for name in names.take(n) {
+            hint::black_box(name?);
+            generated += 1;
         }
+        let elapsed = start.elapsed().as_secs_f64();
+        eprintln!(
+            "benchmark: generated {} names in {:.3}s ({:.0} names/sec)",
+            generated,
+            elapsed,
+            generated as f64 / elapsed
+        );
+        return Ok(());
     }
 
-    Ok(())
+    // Number the names, if requested, counting from 1.
+    let names: Box<dyn Iterator<Item = Result<String, Error>>> = if cli.number {
+        Box::new(
+            (1..)
+                .zip(names)
+                .map(|(ordinal, name)| Ok(format!("{}: {}", ordinal, name?))),
+        )
+    } else {
+        names
+    };
+
+    // Repeat each name, if requested. --count is the number of different
+    // names, so it grows to match.
+    let repeat = cli.repeat.get();
+    let (names, count): (Box<dyn Iterator<Item = Result<String, Error>>>, _) = if repeat > 1 {
+        let names = names.flat_map(move |name| {
+            let (copies, error) = match name {
+                Ok(name) => (Some(std::iter::repeat_n(name, repeat)), None),
+                Err(error) => (None, Some(error)),
+            };
+            copies.into_iter().flatten().map(Ok).chain(error.map(Err))
+        });
+        (
+            Box::new(names),
+            count.map(|count| count.saturating_mul(repeat)),
+        )
+    } else {
+        (names, count)
+    };
+    // Write to the --output file, if given, else to stdout.
+    let mut writer = open_output(cli.output.as_ref())?;
+    let printed = printer(&mut writer, names, disconnect_ok, count, &stop);
+    finish_output(printed, writer, cli.output)
 }
 
-fn common_first_letters(init: &[&str], more: &[&[&str]]) -> HashSet<char> {
-    let mut firsts = first_letters(init);
-    let firsts_other: Vec<HashSet<char>> = more.iter().map(|list| first_letters(list)).collect();
-    firsts.retain(|c| firsts_other.iter().all(|fs| fs.contains(c)));
-    firsts
+// A buffered writer for the --output file, if given, else for stdout.
+fn open_output(output: Option<&path::PathBuf>) -> Result<Box<dyn io::Write>, Error> {
+    Ok(match output {
+        Some(path) => Box::new(io::BufWriter::new(
+            fs::File::create(path).map_err(|e| Error::Output(path.clone(), e))?,
+        )),
+        None => Box::new(io::BufWriter::new(io::stdout().lock())),
+    })
 }
 
-fn first_letters(names: &[&str]) -> HashSet<char> {
-    names.iter().filter_map(|s| s.chars().next()).collect()
+// Dropping a buffered writer discards errors, so flush a file explicitly, and
+// name the file in any error writing to it.
+fn finish_output(
+    printed: Result<(), Error>,
+    mut writer: Box<dyn io::Write>,
+    output: Option<path::PathBuf>,
+) -> Result<(), Error> {
+    match (printed, output) {
+        (Ok(()), Some(path)) => writer.flush().map_err(|e| Error::Output(path, e)),
+        (Err(Error::Io(e)), Some(path)) => Err(Error::Output(path, e)),
+        (printed, _) => printed,
+    }
 }
 
-enum Words {
-    Custom(String, String, String),
-    Builtin,
+// The separator for each gap between words, from --separators if given, else
+// the one --separator. Escape sequences are interpreted in each.
+fn parse_separators(separator: &str, separators: &[String]) -> Vec<String> {
+    if separators.is_empty() {
+        vec![unescape(separator)]
+    } else {
+        separators
+            .iter()
+            .map(|separator| unescape(separator))
+            .collect()
+    }
 }
 
-impl Words {
-    // Load word lists from the given directory. This function expects to find three
-    // files in that directory: `adjectives.txt`, `adverbs.txt`, and `names.txt`.
-    // Each should be valid UTF-8, and contain words separated by whitespace.
-    fn load<T: AsRef<path::Path>>(dirname: T) -> Result<Self, Error> {
-        let dirname = dirname.as_ref();
-        Ok(Self::Custom(
-            read_file_to_string(dirname.join("adjectives.txt"))?,
-            read_file_to_string(dirname.join("adverbs.txt"))?,
-            read_file_to_string(dirname.join("names.txt"))?,
-        ))
+// Interpret the escape sequences \t (tab), \n (newline), \r (carriage
+// return), and \\ (backslash). Any other backslash is left alone.
+fn unescape(s: &str) -> String {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.clone().next() {
+            Some('t') => unescaped.push('\t'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('\\') => unescaped.push('\\'),
+            _ => {
+                unescaped.push('\\');
+                continue;
+            }
+        }
+        chars.next();
     }
+    unescaped
 }
 
-fn read_file_to_string<P: AsRef<path::Path>>(path: P) -> Result<String, Error> {
-    fs::read_to_string(&path).map_err(|error| Error::FileIo(path.as_ref().to_path_buf(), error))
+// Emojis for --emoji.
+const EMOJIS: &[&str] = &["🐱", "🐶", "🦊", "🐼", "🐸", "🦉", "🐢", "🐝"];
+
+fn printer<OUT, NAMES>(
+    writer: &mut OUT,
+    names: NAMES,
+    disconnect_ok: bool,
+    count: Option<usize>,
+    stop: &AtomicBool,
+) -> Result<(), Error>
+where
+    OUT: io::Write,
+    NAMES: Iterator<Item = Result<String, Error>>,
+{
+    let names = names
+        .take(count.unwrap_or(usize::MAX))
+        .take_while(|_| !stop.load(Ordering::Relaxed));
+    if disconnect_ok {
+        for name in names {
+            writeln!(writer, "{}", name?).map_err(suppress_disconnect)?;
+        }
+    } else {
+        for name in names {
+            writeln!(writer, "{}", name?)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn first_letter_counts(names: &[&str]) -> BTreeMap<char, usize> {
+    let mut counts = BTreeMap::new();
+    for c in names.iter().filter_map(|s| s.chars().next()) {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+    counts
 }
 
 fn suppress_disconnect(err: io::Error) -> Error {
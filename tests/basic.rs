@@ -1,60 +1,1317 @@
+use petname::{estimate_syllables, verify_checksum};
+use petname::{
+    is_valid_ident, AdverbPolicy, BinaryError, CardinalityError, Casing, Dictionary, MixedPetnames,
+    PetnameError, Petnames, SeparatorStrategy, WordKind, DEFAULT_RETRIES,
+};
+#[cfg(feature = "default_dictionary")]
+use petname::{iter_all_complexities, Complexity};
 #[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
-use petname::petname;
-use petname::Petnames;
-use rand::rngs::mock::StepRng;
+use petname::{petname, petname_with};
+use rand::RngCore;
+use std::borrow::Cow;
+
+/// A mock RNG that returns an arithmetic sequence of numbers.
+struct StepRng {
+    value: u64,
+    increment: u64,
+}
+
+impl StepRng {
+    fn new(initial: u64, increment: u64) -> Self {
+        Self {
+            value: initial,
+            increment,
+        }
+    }
+}
+
+impl RngCore for StepRng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let value = self.value;
+        self.value = self.value.wrapping_add(self.increment);
+        value
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        rand::rand_core::impls::fill_bytes_via_next(self, dst)
+    }
+}
+
+#[test]
+#[cfg(feature = "default_dictionary")]
+fn default_petnames_has_adjectives() {
+    let petnames = Petnames::default();
+    assert_ne!(petnames.adjectives.len(), 0);
+}
+
+#[test]
+#[cfg(feature = "default_dictionary")]
+fn default_petnames_has_adverbs() {
+    let petnames = Petnames::default();
+    assert_ne!(petnames.adverbs.len(), 0);
+}
+
+#[test]
+#[cfg(feature = "default_dictionary")]
+fn default_petnames_has_names() {
+    let petnames = Petnames::default();
+    assert_ne!(petnames.names.len(), 0);
+}
+
+#[test]
+fn petname_error_display() {
+    assert_eq!(
+        "no adverbs given",
+        PetnameError::EmptyList(WordKind::Adverb).to_string()
+    );
+    assert_eq!(
+        "cannot alliterate: no words begin with 'q'",
+        PetnameError::Alliteration(Some('q')).to_string()
+    );
+    assert_eq!(
+        "cannot alliterate: word lists have no initial letters in common",
+        PetnameError::Alliteration(None).to_string()
+    );
+    #[cfg(feature = "std")]
+    assert_eq!(
+        "cannot read word lists:\n  a.txt: entity not found\n  b.txt: permission denied",
+        PetnameError::FileIo(vec![
+            ("a.txt".into(), std::io::ErrorKind::NotFound),
+            ("b.txt".into(), std::io::ErrorKind::PermissionDenied),
+        ])
+        .to_string()
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn dictionary_load_dir_reports_every_missing_file() {
+    let dir = std::env::temp_dir().join(format!("petname-basic-{}-load", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("adverbs.txt"), "very").unwrap();
+    match Dictionary::load_dir(&dir) {
+        Err(PetnameError::FileIo(errors)) => {
+            assert_eq!(
+                vec![
+                    (dir.join("adjectives.txt"), std::io::ErrorKind::NotFound),
+                    (dir.join("names.txt"), std::io::ErrorKind::NotFound),
+                ],
+                errors
+            );
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    std::fs::write(dir.join("adjectives.txt"), "happy").unwrap();
+    std::fs::write(dir.join("names.txt"), "cat").unwrap();
+    let dictionary = Dictionary::load_dir(&dir).unwrap();
+    assert_eq!(
+        Petnames::init("happy", "very", "cat"),
+        dictionary.petnames()
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn retain_alliterating_with_letter() {
+    let mut rng = StepRng::new(0, 1);
+    let mut petnames = Petnames::init("able bad", "very bold", "ant bat");
+    assert_eq!(
+        Err(PetnameError::Alliteration(Some('a'))),
+        petnames.retain_alliterating(&mut rng, Some('a'))
+    );
+    assert_eq!(Petnames::init("able bad", "very bold", "ant bat"), petnames);
+    assert_eq!(Ok('b'), petnames.retain_alliterating(&mut rng, Some('b')));
+    assert_eq!(Petnames::init("bad", "bold", "bat"), petnames);
+    let mut petnames = Petnames::init("able", "very", "cat");
+    assert_eq!(
+        Err(PetnameError::Alliteration(None)),
+        petnames.retain_alliterating(&mut rng, None)
+    );
+}
+
+#[test]
+fn try_init_accepts_non_empty_word_lists() {
+    assert_eq!(
+        Ok(Petnames::init("happy", "very", "cat")),
+        Petnames::try_init("happy", "very", "cat")
+    );
+}
+
+#[test]
+fn try_init_names_the_empty_category() {
+    let error = Petnames::try_init("happy", "very", " \n").unwrap_err();
+    assert_eq!(PetnameError::EmptyList(WordKind::Name), error);
+    assert_eq!("no names given", error.to_string());
+    assert_eq!(
+        Err(PetnameError::EmptyList(WordKind::Adjective)),
+        Petnames::try_init("", "", "")
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn dictionary_from_readers_reads_words_from_each_line() {
+    use std::io::BufReader;
+    // A tiny buffer, so that lines and words span several reads.
+    let reader = |text: &'static str| BufReader::with_capacity(4, text.as_bytes());
+    let dictionary = Dictionary::from_readers(
+        reader("happy sad\n\n  calm\r\n"),
+        reader(""),
+        reader("cat\ndog eel"),
+    )
+    .unwrap();
+    assert_eq!(
+        Petnames::init("happy sad calm", "", "cat dog eel"),
+        dictionary.petnames()
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn dictionary_from_readers_reports_read_errors() {
+    use std::io::Cursor;
+    let invalid: &[u8] = &[b'a', 0xff, b'\n'];
+    let result = Dictionary::from_readers(
+        Cursor::new(&b"happy"[..]),
+        Cursor::new(invalid),
+        Cursor::new(&b"cat"[..]),
+    );
+    assert_eq!(std::io::ErrorKind::InvalidData, result.unwrap_err().kind());
+}
+
+#[test]
+fn dictionary_from_single_list_uses_it_for_every_list() {
+    let dictionary = Dictionary::from_single_list("happy cat".to_string());
+    assert_eq!(
+        Petnames::from_single_list("happy cat"),
+        dictionary.petnames()
+    );
+}
+
+#[test]
+#[cfg(feature = "arbitrary")]
+fn arbitrary_petnames_from_fixed_bytes() {
+    use arbitrary::{Arbitrary, Unstructured};
+    let bytes = b"happy sad\x09very\x09cat dog\x09\x01";
+    let petnames = Petnames::arbitrary(&mut Unstructured::new(bytes)).unwrap();
+    // `Unstructured` takes the length of each string from the end of the
+    // buffer, so the words are not split where they appear to be.
+    assert_eq!(
+        Petnames::init("h", "appy sad", "very ca").with_adverb_policy(AdverbPolicy::None),
+        petnames
+    );
+    assert_eq!(
+        petnames,
+        Petnames::arbitrary(&mut Unstructured::new(bytes)).unwrap()
+    );
+}
+
+#[test]
+fn extend_adds_tagged_words_to_matching_lists() {
+    use WordKind::{Adjective, Adverb, Name};
+    let mut petnames = Petnames::init("", "", "");
+    petnames.extend(
+        [
+            (Name, "cat"),
+            (Adjective, "happy"),
+            (Adverb, "very"),
+            (Name, "dog"),
+            (Adjective, "sad"),
+        ]
+        .iter()
+        .copied(),
+    );
+    assert_eq!(Petnames::init("happy sad", "very", "cat dog"), petnames);
+}
+
+#[test]
+fn from_slices_uses_words_as_given() {
+    const ADJECTIVES: &[&str] = &["happy", "sad"];
+    let petnames = Petnames::from_slices(ADJECTIVES, &[], &["cat", "big dog"]);
+    assert_eq!(vec!["happy", "sad"], petnames.adjectives);
+    assert!(petnames.adverbs.is_empty());
+    assert_eq!(vec!["cat", "big dog"], petnames.names);
+    assert_eq!(
+        "happy-cat",
+        petnames.generate(&mut StepRng::new(0, 1), 2, "-")
+    );
+}
+
+#[test]
+fn from_single_list_uses_list_for_every_category() {
+    let petnames = Petnames::from_single_list("alice bob carol");
+    assert_eq!(
+        Petnames::init("alice bob carol", "alice bob carol", "alice bob carol"),
+        petnames
+    );
+    assert_eq!(3, petnames.cardinality(1));
+    assert_eq!(81, petnames.cardinality(4));
+    let name = petnames.generate(&mut StepRng::new(0, 1), 4, "-");
+    assert_eq!(4, name.split('-').count());
+}
+
+#[test]
+#[cfg(feature = "default_dictionary")]
+fn binary_format_round_trips() {
+    let petnames = Petnames::small();
+    let bytes = petnames.to_binary();
+    assert_eq!(Ok(petnames), Petnames::from_binary(&bytes));
+}
+
+#[test]
+fn binary_format_rejects_malformed_input() {
+    let bytes = Petnames::init("happy", "very", "cat").to_binary();
+    let truncated = &bytes[..bytes.len() - 1];
+    assert_eq!(
+        Err(BinaryError::Truncated),
+        Petnames::from_binary(truncated)
+    );
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(
+        Err(BinaryError::TrailingBytes),
+        Petnames::from_binary(&trailing)
+    );
+    let mut invalid = bytes;
+    invalid[4 + 4] = 0xff;
+    assert_eq!(
+        Err(BinaryError::InvalidUtf8),
+        Petnames::from_binary(&invalid)
+    );
+}
+
+#[test]
+fn clear_empties_every_word_list() {
+    let mut petnames =
+        Petnames::init("happy", "very", "cat").with_adverb_policy(AdverbPolicy::None);
+    petnames.clear();
+    assert_eq!(
+        Petnames::init("", "", "").with_adverb_policy(AdverbPolicy::None),
+        petnames
+    );
+}
+
+#[test]
+fn set_names_replaces_only_names() {
+    let mut petnames = Petnames::init("happy", "very", "cat");
+    petnames.set_names(vec!["dog", "eel"]);
+    assert_eq!(Petnames::init("happy", "very", "dog eel"), petnames);
+    let mut rng = StepRng::new(0, 1);
+    assert_eq!("very-happy-dog", petnames.generate(&mut rng, 3, "-"));
+    petnames.set_adjectives(vec!["sad"]);
+    petnames.set_adverbs(Vec::new());
+    assert_eq!("sad-dog", petnames.generate(&mut rng, 3, "-"));
+}
+
+#[test]
+fn retain_applies_given_predicate() {
+    let petnames_expected = Petnames::init("bob", "bob", "bob jane");
+    let mut petnames = Petnames::init("alice bob carol", "alice bob", "bob carol jane");
+    petnames.retain(|word| word.len() < 5);
+    assert_eq!(petnames_expected, petnames);
+}
+
+#[test]
+fn shards_cover_word_lists_without_overlap() {
+    let petnames = Petnames::init("a b c d e f g", "h i j", "k l m n o p q r s t");
+    let shards: Vec<Petnames> = (0..4).map(|index| petnames.shard(index, 4)).collect();
+    // Concatenating the shards in order yields each original list, so every
+    // word is in exactly one shard.
+    let mut union = Petnames::init("", "", "");
+    for shard in shards {
+        union.adjectives.extend(shard.adjectives);
+        union.adverbs.extend(shard.adverbs);
+        union.names.extend(shard.names);
+    }
+    assert_eq!(petnames, union);
+}
+
+#[test]
+#[cfg(feature = "std_rng")]
+fn partition_splits_word_lists_into_disjoint_parts() {
+    use rand::{rngs::StdRng, SeedableRng};
+    let petnames = Petnames::init("a b c d e f g h", "i j", "k l m n o p q r s t");
+    let (train, validate) = petnames.partition(&mut StdRng::seed_from_u64(3), 0.7);
+    for (list, first, second) in [
+        (
+            &petnames.adjectives,
+            &train.adjectives,
+            &validate.adjectives,
+        ),
+        (&petnames.adverbs, &train.adverbs, &validate.adverbs),
+        (&petnames.names, &train.names, &validate.names),
+    ] {
+        assert!(first.iter().all(|word| !second.contains(word)));
+        let mut union: Vec<&str> = first.iter().chain(second.iter()).copied().collect();
+        union.sort_unstable();
+        assert_eq!(*list, union);
+    }
+    assert_eq!(
+        (6, 1, 7),
+        (
+            train.adjectives.len(),
+            train.adverbs.len(),
+            train.names.len()
+        )
+    );
+    // The same seed gives the same partition.
+    assert_eq!(
+        (train, validate),
+        petnames.partition(&mut StdRng::seed_from_u64(3), 0.7)
+    );
+}
+
+#[test]
+#[should_panic(expected = "shard index 3 out of range 0..3")]
+fn shard_index_must_be_less_than_total() {
+    Petnames::init("a", "b", "c").shard(3, 3);
+}
+
+#[test]
+fn retain_printable_removes_control_and_zero_width_words() {
+    let petnames_expected = Petnames::init("bob", "alice", "carol");
+    let mut petnames = Petnames::init("bob b\u{200b}ob", "alice \u{7}alice", "carol ca\u{feff}rol");
+    petnames.retain_printable();
+    assert_eq!(petnames_expected, petnames);
+}
+
+#[test]
+fn retain_initials_keeps_only_words_with_allowed_initials() {
+    let mut petnames = Petnames::init("able bad eager", "oddly very", "ant bee Owl");
+    assert_eq!(18, petnames.cardinality(3));
+    petnames.retain_initials(&['a', 'e', 'i', 'o', 'u']);
+    assert_eq!(Petnames::init("able eager", "oddly", "ant"), petnames);
+    assert_eq!(2, petnames.cardinality(3));
+}
+
+#[test]
+fn retain_initials_ignore_case_keeps_words_with_either_case() {
+    let mut petnames = Petnames::init("Able bad", "oddly", "ant Owl bee");
+    petnames.retain_initials_ignore_case(&['A', 'o']);
+    assert_eq!(Petnames::init("Able", "oddly", "ant Owl"), petnames);
+}
+
+#[test]
+fn retain_with_metadata_passes_word_kinds() {
+    let mut seen = Vec::new();
+    let mut petnames = Petnames::init("a", "b", "c");
+    petnames.retain_with_metadata(|kind, word| {
+        seen.push((kind, word.to_string()));
+        kind == WordKind::Adverb
+    });
+    assert_eq!(Petnames::init("", "b", ""), petnames);
+    assert_eq!(
+        vec![
+            (WordKind::Adjective, "a".to_string()),
+            (WordKind::Adverb, "b".to_string()),
+            (WordKind::Name, "c".to_string()),
+        ],
+        seen
+    );
+}
+
+#[test]
+fn retain_len_filters_each_category_independently() {
+    let mut petnames = Petnames::init("big bigger biggest", "so very truly", "ox cat elephant");
+    petnames.retain_adjectives_len(2, 3);
+    assert_eq!(
+        Petnames::init("big", "so very truly", "ox cat elephant"),
+        petnames
+    );
+    petnames.retain_adverbs_len(4, 5);
+    assert_eq!(
+        Petnames::init("big", "very truly", "ox cat elephant"),
+        petnames
+    );
+    petnames.retain_names_len(3, 10);
+    assert_eq!(
+        Petnames::init("big", "very truly", "cat elephant"),
+        petnames
+    );
+    // Characters are counted, not bytes.
+    let mut petnames = Petnames::init("", "", "café cafés");
+    petnames.retain_names_len(4, 4);
+    assert_eq!(Petnames::init("", "", "café"), petnames);
+}
+
+#[test]
+fn retain_no_confusables_keeps_one_word_per_group() {
+    let mut petnames = Petnames::init("knight night", "very", "night nite cat");
+    petnames.retain_no_confusables(&[&["night", "knight", "nite"]]);
+    assert_eq!(Petnames::init("knight", "very", "night cat"), petnames);
+}
+
+#[test]
+fn matches_shape_checks_word_count_and_categories() {
+    let petnames = Petnames::init("happy sad", "very", "cat dog");
+    assert!(petnames.matches_shape("very-sad-dog", 3, "-"));
+    assert!(petnames.matches_shape("happy-cat", 2, "-"));
+    assert!(petnames.matches_shape("cat", 1, "-"));
+    assert!(petnames.matches_shape("", 0, "-"));
+    // Wrong word counts.
+    assert!(!petnames.matches_shape("sad-dog", 3, "-"));
+    assert!(!petnames.matches_shape("very-sad-dog", 2, "-"));
+    assert!(!petnames.matches_shape("very-sad-dog-", 3, "-"));
+    assert!(!petnames.matches_shape("", 1, "-"));
+    // Words in the wrong places, or not in the lists at all.
+    assert!(!petnames.matches_shape("dog-sad", 2, "-"));
+    assert!(!petnames.matches_shape("sad-very-dog", 3, "-"));
+    assert!(!petnames.matches_shape("sad-cow", 2, "-"));
+    assert!(!petnames.matches_shape("sad_dog", 2, "-"));
+}
+
+#[test]
+fn matches_shape_follows_adverb_policy_and_skips_empty_lists() {
+    let petnames = Petnames::init("happy", "very", "cat").with_adverb_policy(AdverbPolicy::None);
+    assert!(petnames.matches_shape("happy-happy-cat", 3, "-"));
+    assert!(!petnames.matches_shape("very-happy-cat", 3, "-"));
+    let petnames = Petnames::init("happy", "", "cat");
+    assert!(petnames.matches_shape("happy-cat", 3, "-"));
+    assert!(petnames.matches_shape("cat", 1, ""));
+    assert!(!petnames.matches_shape("happycat", 2, ""));
+}
+
+#[test]
+fn retain_minimal_removes_words_prefixed_by_other_words() {
+    let petnames_expected = Petnames::init("big", "cat", "ñu");
+    let mut petnames = Petnames::init("big bigger", "cats cat", "ñu ñus");
+    petnames.retain_minimal();
+    assert_eq!(petnames_expected, petnames);
+}
+
+#[test]
+fn intersect_keeps_only_shared_words() {
+    let mut petnames = Petnames::init("able bad calm", "very", "ant bee cat Dog");
+    let other = Petnames::init("calm able dire", "quite very", "dog cat bee");
+    petnames.intersect(&other);
+    assert_eq!(Petnames::init("able calm", "very", "bee cat"), petnames);
+    assert_eq!(4, petnames.cardinality(2));
+    assert_eq!(4, petnames.cardinality(3));
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn retain_regex_keeps_only_matching_words() {
+    let mut petnames = Petnames::init("able bad nice", "madly oddly", "cat mole zebu");
+    petnames.retain_regex("^[a-m]").unwrap();
+    assert_eq!(Petnames::init("able bad", "madly", "cat mole"), petnames);
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn retain_regex_rejects_invalid_pattern() {
+    let mut petnames = Petnames::init("able", "madly", "cat");
+    assert!(petnames.retain_regex("[a-").is_err());
+    assert_eq!(Petnames::init("able", "madly", "cat"), petnames);
+}
+
+#[test]
+#[cfg(feature = "blocklist")]
+fn retain_safe_removes_blocked_words_ignoring_case() {
+    let mut petnames = Petnames::init("happy Crap", "very", "cat Twat class");
+    petnames.retain_safe();
+    assert_eq!(Petnames::init("happy", "very", "cat class"), petnames);
+}
+
+#[test]
+fn retain_ascii_removes_non_ascii_words() {
+    let petnames_expected = Petnames::init("bob", "alice", "");
+    let mut petnames = Petnames::init("bob bøb", "alice αλίκη", "кэрол キャロル");
+    petnames.retain_ascii();
+    assert_eq!(petnames_expected, petnames);
+    assert_eq!(0, petnames.cardinality(2));
+}
+
+#[test]
+fn require_cardinality_fails_below_minimum() {
+    let petnames = Petnames::init("a b c", "d e", "f g h i");
+    assert_eq!(Ok(()), petnames.require_cardinality(3, 24));
+    assert_eq!(
+        Err(CardinalityError {
+            words: 3,
+            cardinality: 24,
+            minimum: 25
+        }),
+        petnames.require_cardinality(3, 25)
+    );
+}
+
+#[test]
+#[cfg(feature = "default_dictionary")]
+fn default_petnames_has_non_zero_cardinality() {
+    let petnames = Petnames::default();
+    // This test will need to be adjusted when word lists change.
+    assert_eq!(1, petnames.cardinality(0));
+    assert_eq!(456, petnames.cardinality(1));
+    assert_eq!(204744, petnames.cardinality(2));
+    assert_eq!(53438184, petnames.cardinality(3));
+    assert_eq!(13947366024, petnames.cardinality(4));
+}
+
+#[test]
+fn cardinality_checked_detects_overflow() {
+    // 16 adverbs, 17 adjectives, 1 name: with 31 adverbs, 2^124 × 17 > 2^128.
+    let adverbs = (0..16)
+        .map(|n| format!("a{}", n))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let adjectives = (0..17)
+        .map(|n| format!("b{}", n))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let petnames = Petnames::init(&adjectives, &adverbs, "c");
+    assert_eq!(Some(16u128.pow(30) * 17), petnames.cardinality_checked(32));
+    assert_eq!(None, petnames.cardinality_checked(33));
+    assert_eq!(u128::MAX, petnames.cardinality(33));
+}
+
+#[test]
+fn frozen_petnames_cardinality_matches_live_cardinality() {
+    let petnames = Petnames::init("a b", "c d e", "f g h i");
+    let frozen = petnames.clone().freeze();
+    for words in 0..=u8::MAX {
+        assert_eq!(petnames.cardinality(words), frozen.cardinality(words));
+    }
+    assert_eq!(petnames, frozen.thaw());
+}
+
+#[test]
+#[cfg(feature = "std_rng")]
+fn mixed_petnames_follows_weights() {
+    use rand::{rngs::StdRng, SeedableRng};
+    let mut rng = StdRng::seed_from_u64(5);
+    let mixed = MixedPetnames::new()
+        .with(Petnames::init("small", "", "cat"), 7)
+        .with(Petnames::init("large", "", "cat"), 3)
+        .with(Petnames::init("never", "", "cat"), 0);
+    let small = (0..10_000)
+        .map(|_| mixed.generate(&mut rng, 2, "-"))
+        .inspect(|name| assert!(!name.starts_with("never")))
+        .filter(|name| name.starts_with("small"))
+        .count();
+    assert!((6_800..7_200).contains(&small), "{} small names", small);
+}
+
+#[test]
+fn mixed_petnames_cardinality_sums_weighted_components() {
+    let mixed = MixedPetnames::new()
+        .with(Petnames::init("a b", "", "c"), 1)
+        .with(Petnames::init("d", "", "e f g"), 2)
+        .with(Petnames::init("h", "", "i"), 0);
+    assert_eq!(5, mixed.cardinality(2));
+    assert_eq!(0, MixedPetnames::new().cardinality(2));
+}
+
+#[test]
+fn mixed_petnames_without_weights_generates_empty_names() {
+    let mixed = MixedPetnames::new().with(Petnames::init("a", "", "b"), 0);
+    assert_eq!("", mixed.generate(&mut StepRng::new(0, 1), 2, "-"));
+}
+
+#[test]
+fn max_name_length_matches_longest_possible_name() {
+    let mut rng = StepRng::new(0, 1);
+    let petnames = Petnames::init("a bbb cc", "dddd e", "ff g");
+    for words in 1..5 {
+        let longest = petnames
+            .iter_non_repeating(&mut rng, words, "::")
+            .map(|name| name.len())
+            .max()
+            .unwrap();
+        assert_eq!(longest, petnames.max_name_length(words, "::"));
+    }
+}
+
+#[test]
+fn max_name_length_skips_empty_lists() {
+    let petnames = Petnames::init("", "very", "cat");
+    assert_eq!("very-cat".len(), petnames.max_name_length(3, "-"));
+    assert_eq!(0, Petnames::init("", "", "").max_name_length(3, "-"));
+}
+
+#[test]
+fn fingerprint_is_equal_for_equal_petnames() {
+    let petnames1 = Petnames::init("alice bob", "carol", "dave eve");
+    let petnames2 = Petnames::init("alice bob", "carol", "dave eve");
+    assert_eq!(petnames1.fingerprint(), petnames2.fingerprint());
+}
+
+#[test]
+fn fingerprint_differs_for_modified_petnames() {
+    let petnames = Petnames::init("alice bob", "carol", "dave eve");
+    let mut modified = petnames.clone();
+    modified.retain(|word| word != "eve");
+    assert_ne!(petnames.fingerprint(), modified.fingerprint());
+    // Moving a word between lists also changes the fingerprint.
+    let moved = Petnames::init("alice", "bob carol", "dave eve");
+    assert_ne!(petnames.fingerprint(), moved.fingerprint());
+}
+
+#[test]
+fn separator_is_safe_detects_separator_in_words() {
+    let petnames = Petnames::init("well-known", "very", "cat");
+    assert!(!petnames.separator_is_safe("-"));
+    assert!(petnames.separator_is_safe("_"));
+    assert!(!petnames.separator_is_safe(""));
+}
+
+#[test]
+fn length_histogram_sums_to_total_word_count() {
+    let petnames = Petnames::init("happy sad", "very", "cat dog élan");
+    let histogram = petnames.length_histogram();
+    assert_eq!(6, histogram.values().sum::<usize>());
+    assert_eq!(Some(&2), histogram.get(&4));
+}
+
+#[test]
+#[cfg(feature = "default_dictionary")]
+fn length_histogram_of_default_petnames_counts_every_word() {
+    let petnames = Petnames::default();
+    let total = petnames.adjectives.len() + petnames.adverbs.len() + petnames.names.len();
+    assert_eq!(total, petnames.length_histogram().values().sum::<usize>());
+}
+
+#[test]
+#[cfg(feature = "default_dictionary")]
+fn memory_footprint_of_small_petnames_is_reasonable() {
+    let petnames = Petnames::small();
+    let words = petnames.adjectives.len() + petnames.adverbs.len() + petnames.names.len();
+    let footprint = petnames.memory_footprint();
+    // At least a reference and a few bytes for every word, but not huge.
+    assert!(footprint > words * (std::mem::size_of::<&str>() + 3));
+    assert!(footprint < 1024 * 1024);
+}
+
+#[test]
+fn memory_footprint_counts_words_and_lists() {
+    let petnames = Petnames {
+        adjectives: vec!["abc"],
+        adverbs: vec![],
+        names: vec!["de", "f"],
+        adverb_policy: AdverbPolicy::Linear,
+    };
+    let list = std::mem::size_of::<Vec<&str>>();
+    let word = std::mem::size_of::<&str>();
+    assert_eq!(3 * list + 3 * word + 6, petnames.memory_footprint());
+}
+
+#[test]
+fn generate_uses_adverb_adjective_name() {
+    let petnames = Petnames {
+        adjectives: vec!["adjective"],
+        adverbs: vec!["adverb"],
+        names: vec!["name"],
+        adverb_policy: AdverbPolicy::Linear,
+    };
+    assert_eq!(
+        petnames.generate(&mut StepRng::new(0, 1), 3, "-"),
+        "adverb-adjective-name"
+    );
+}
+
+#[test]
+#[cfg(feature = "std_rng")]
+fn generate_works_with_thread_local_rng() {
+    let petnames = Petnames::init("adjective", "adverb", "name");
+    let name = petnames.generate(&mut rand::rng(), 3, "-");
+    assert_eq!("adverb-adjective-name", name);
+}
+
+#[test]
+fn generate_dyn_accepts_rng_trait_object() {
+    let petnames = Petnames::init("adjective", "adverb", "name");
+    let mut rng: Box<dyn RngCore> = Box::new(StepRng::new(0, 1));
+    assert_eq!(
+        "adverb-adjective-name",
+        petnames.generate_dyn(&mut *rng, 3, "-")
+    );
+}
+
+#[test]
+fn generate_returns_empty_name_when_all_word_lists_are_empty() {
+    let petnames = Petnames::init("", "", "");
+    let mut rng = StepRng::new(0, 1);
+    for words in 0..=u8::MAX {
+        assert_eq!("", petnames.generate(&mut rng, words, "-"));
+    }
+}
+
+#[test]
+fn generate_returns_empty_name_for_zero_words() {
+    let petnames = Petnames::init("able", "very", "ant");
+    let mut rng = StepRng::new(0, 1);
+    assert_eq!(1, petnames.cardinality(0));
+    assert_eq!(1, Petnames::init("", "", "").cardinality(0));
+    assert_eq!("", petnames.generate(&mut rng, 0, "-"));
+}
+
+#[test]
+fn generate_with_emoji_appends_one_of_the_emojis() {
+    let petnames = Petnames::init("able", "very", "ant");
+    let emojis = ["🐜", "🐝"];
+    let mut rng = StepRng::new(0, 1 << 31);
+    for _ in 0..4 {
+        let name = petnames.generate_with_emoji(&mut rng, 2, "-", &emojis);
+        assert!(emojis
+            .iter()
+            .any(|emoji| name == format!("able-ant{}", emoji)));
+    }
+    assert_eq!(
+        "able-ant",
+        petnames.generate_with_emoji(&mut rng, 2, "-", &[])
+    );
+}
+
+#[test]
+fn generate_skips_empty_word_lists() {
+    let mut rng = StepRng::new(0, 1);
+    let no_adverbs = Petnames::init("adjective", "", "name");
+    assert_eq!("adjective-name", no_adverbs.generate(&mut rng, 4, "-"));
+    let no_adjectives = Petnames::init("", "adverb", "name");
+    assert_eq!(
+        "adverb-adverb-name",
+        no_adjectives.generate(&mut rng, 4, "-")
+    );
+    let no_names = Petnames::init("adjective", "adverb", "");
+    assert_eq!(
+        "adverb-adverb-adjective",
+        no_names.generate(&mut rng, 4, "-")
+    );
+    assert_eq!("", no_names.generate(&mut rng, 1, "-"));
+}
+
+#[test]
+fn generate_output_is_stable() {
+    let mut rng = StepRng::new(0, 1 << 30);
+    let petnames = Petnames::init("b1 b2 b3 b4", "a1 a2 a3 a4", "c1 c2 c3 c4");
+    let names: Vec<String> = (0..4)
+        .map(|words| petnames.generate(&mut rng, words + 2, &["-", "_"][..]))
+        .collect();
+    assert_eq!(
+        vec!["b1-c2", "a3-b4_c1", "a2-a3_b4_c1", "a2-a3_a4_b1_c2"],
+        names
+    );
+}
+
+#[test]
+#[cfg(feature = "std_rng")]
+fn generate_reversed_reverses_the_words_of_generate() {
+    use rand::{rngs::StdRng, SeedableRng};
+    let petnames = Petnames::init("able bad calm", "oddly very", "ant bee cat");
+    for seed in 0..10 {
+        let forward = petnames.generate(&mut StdRng::seed_from_u64(seed), 4, "-");
+        let reversed = petnames.generate_reversed(&mut StdRng::seed_from_u64(seed), 4, "-");
+        let mut words: Vec<&str> = forward.split('-').collect();
+        words.reverse();
+        assert_eq!(words.join("-"), reversed);
+    }
+}
+
+#[test]
+fn adjective_noun_matches_two_word_generate() {
+    let petnames =
+        Petnames::init("happy sad", "very", "cat dog").with_adverb_policy(AdverbPolicy::None);
+    for (start, increment) in [(0, 1), (0, 1 << 31), (1 << 31, 1)] {
+        assert_eq!(
+            petnames.generate(&mut StepRng::new(start, increment), 2, "+"),
+            petnames.adjective_noun(&mut StepRng::new(start, increment), "+")
+        );
+    }
+    let name = petnames.adjective_noun(&mut StepRng::new(0, 1 << 31), "+");
+    let words: Vec<&str> = name.split('+').collect();
+    assert_eq!(2, words.len());
+    assert!(petnames.adjectives.contains(&words[0]));
+    assert!(petnames.names.contains(&words[1]));
+}
+
+#[test]
+fn generate_template_fills_placeholders_from_their_lists() {
+    let petnames = Petnames::init("happy sad", "very", "cat dog");
+    let template = "the {adjective} {name} who runs {adverb} from the {name} {{{colour}}} {";
+    let mut rng = StepRng::new(0, 1 << 31);
+    let name = petnames.generate_template(&mut rng, template);
+    let words: Vec<&str> = name.split(' ').collect();
+    assert_eq!(
+        ["the", "who", "runs", "from", "the", "{{colour}}", "{"],
+        [words[0], words[3], words[4], words[6], words[7], words[9], words[10]]
+    );
+    assert!(petnames.adjectives.contains(&words[1]));
+    assert!(petnames.names.contains(&words[2]));
+    assert!(petnames.adverbs.contains(&words[5]));
+    assert!(petnames.names.contains(&words[8]));
+    // Repeated placeholders are drawn independently.
+    let mut rng = StepRng::new(0, 1 << 31);
+    assert_eq!(
+        "cat dog",
+        petnames.generate_template(&mut rng, "{name} {name}")
+    );
+}
+
+#[test]
+fn noun_only_matches_one_word_generate() {
+    let petnames = Petnames::init("happy sad", "very", "cat dog");
+    let name = petnames.noun_only(&mut StepRng::new(0, 1 << 31));
+    assert_eq!(
+        petnames.generate(&mut StepRng::new(0, 1 << 31), 1, "-"),
+        name
+    );
+    assert!(petnames.names.contains(&name.as_str()));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn generate_buffered_reuses_its_buffer() {
+    let petnames = Petnames::init("aa bb", "cc dd", "ee ff");
+    let mut rng = StepRng::new(0, 1 << 31);
+    let mut buffers = std::collections::BTreeSet::new();
+    for _ in 0..1000 {
+        let name = petnames.generate_buffered(&mut rng, 3, "-", |name| {
+            buffers.insert(name.as_ptr() as usize);
+            name.to_string()
+        });
+        assert_eq!(8, name.len());
+    }
+    // All of the names were written to the same allocation.
+    assert_eq!(1, buffers.len());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn generate_buffered_matches_generate() {
+    let petnames = Petnames::init("aa bb", "cc dd", "ee ff");
+    let expected = petnames.generate(&mut StepRng::new(0, 1 << 31), 3, "-");
+    let name = petnames.generate_buffered(&mut StepRng::new(0, 1 << 31), 3, "-", |name| {
+        // Nesting works, with its own buffer.
+        let inner = petnames.generate_buffered(&mut StepRng::new(0, 1), 2, "+", str::to_string);
+        assert_eq!("aa+ee", inner);
+        name.to_string()
+    });
+    assert_eq!(expected, name);
+}
+
+#[test]
+fn generate_uses_constant_separator_strategy() {
+    let petnames = Petnames::init("b", "a", "c");
+    let separator = String::from("+");
+    assert_eq!(
+        "a+a+b+c",
+        petnames.generate(&mut StepRng::new(0, 1), 4, &separator)
+    );
+}
+
+#[test]
+fn generate_uses_custom_separator_strategy() {
+    struct Alternating<'s>([&'s str; 2]);
+
+    impl SeparatorStrategy for Alternating<'_> {
+        fn separator(&self, gap_index: usize) -> &str {
+            self.0[gap_index % 2]
+        }
+    }
+
+    let petnames = Petnames::init("b", "a", "c");
+    let name = petnames.generate(&mut StepRng::new(0, 1), 5, Alternating([".", "::"]));
+    assert_eq!("a.a::a.b::c", name);
+}
+
+#[test]
+fn generate_tagged_follows_documented_composition() {
+    use WordKind::{Adjective, Adverb, Name};
+    let petnames = Petnames::init("adjective", "adverb", "name");
+    let mut rng = StepRng::new(0, 1);
+    let mut kinds = |words| -> Vec<WordKind> {
+        petnames
+            .generate_tagged(&mut rng, words)
+            .into_iter()
+            .map(|(kind, _)| kind)
+            .collect()
+    };
+    assert_eq!(vec![Name], kinds(1));
+    assert_eq!(vec![Adjective, Name], kinds(2));
+    assert_eq!(vec![Adverb, Adjective, Name], kinds(3));
+    assert_eq!(vec![Adverb, Adverb, Adjective, Name], kinds(4));
+}
+
+#[test]
+fn adverb_policies_compose_four_word_names() {
+    use WordKind::{Adjective, Adverb, Name};
+    let mut rng = StepRng::new(0, 1);
+    let mut kinds = |policy| -> Vec<WordKind> {
+        Petnames::init("adjective", "adverb", "name")
+            .with_adverb_policy(policy)
+            .generate_tagged(&mut rng, 4)
+            .into_iter()
+            .map(|(kind, _)| kind)
+            .collect()
+    };
+    assert_eq!(
+        vec![Adverb, Adverb, Adjective, Name],
+        kinds(AdverbPolicy::Linear)
+    );
+    assert_eq!(
+        vec![Adverb, Adjective, Adjective, Name],
+        kinds(AdverbPolicy::AtMostOne)
+    );
+    assert_eq!(
+        vec![Adjective, Adjective, Adjective, Name],
+        kinds(AdverbPolicy::None)
+    );
+}
+
+#[test]
+fn adverb_policy_affects_cardinality() {
+    let petnames = Petnames::init("a b", "c d e", "f g h i");
+    assert_eq!(24, petnames.cardinality(3));
+    let petnames = petnames.with_adverb_policy(AdverbPolicy::None);
+    assert_eq!(16, petnames.cardinality(3));
+}
+
+#[test]
+fn generate_tagged_pairs_words_with_kinds() {
+    let petnames = Petnames::init("adjective", "adverb", "name");
+    assert_eq!(
+        vec![
+            (WordKind::Adverb, "adverb"),
+            (WordKind::Adjective, "adjective"),
+            (WordKind::Name, "name"),
+        ],
+        petnames.generate_tagged(&mut StepRng::new(0, 1), 3)
+    );
+}
+
+#[test]
+fn generate_with_noun_transform_transforms_only_the_name() {
+    let petnames = Petnames::init("cat", "cat", "cat");
+    let name = petnames.generate_with_noun_transform(&mut StepRng::new(0, 1), 4, "-", |noun| {
+        format!("<{}>", noun)
+    });
+    assert_eq!("cat-cat-cat-<cat>", name);
+}
+
+#[test]
+fn generate_plural_noun_appends_s_to_the_name() {
+    let petnames = Petnames::init("happy", "very", "cat");
+    let name = petnames.generate_plural_noun(&mut StepRng::new(0, 1), 3, "-");
+    assert_eq!("very-happy-cats", name);
+}
+
+#[test]
+fn generate_cased_with_sentence_case_capitalizes_first_letter_only() {
+    let petnames = Petnames::init("Adjective", "ádverb", "NAME");
+    let name = petnames.generate_cased(&mut StepRng::new(0, 1), 3, "-", Casing::Sentence);
+    assert_eq!("Ádverb-adjective-name", name);
+}
 
 #[test]
-#[cfg(feature = "default_dictionary")]
-fn default_petnames_has_adjectives() {
-    let petnames = Petnames::default();
-    assert_ne!(petnames.adjectives.len(), 0);
+fn generate_cased_with_title_case_capitalizes_every_word() {
+    let petnames = Petnames::init("adjective", "straße", "NAME");
+    let name = petnames.generate_cased(&mut StepRng::new(0, 1), 3, "-", Casing::Title);
+    assert_eq!("Straße-Adjective-Name", name);
 }
 
 #[test]
-#[cfg(feature = "default_dictionary")]
-fn default_petnames_has_adverbs() {
-    let petnames = Petnames::default();
-    assert_ne!(petnames.adverbs.len(), 0);
+fn generate_cased_uses_unicode_default_case_mapping_for_dotted_and_dotless_i() {
+    // Turkish rules would give "Irmak-İyi-İnce" and "ırmak-iyi-ince".
+    let petnames = Petnames::init("İYİ", "ırmak", "ince");
+    let mut rng = StepRng::new(0, 1);
+    let title = petnames.generate_cased(&mut rng, 3, "-", Casing::Title);
+    assert_eq!("Irmak-İyi\u{307}-Ince", title);
+    let lower = petnames.generate_cased(&mut rng, 3, "-", Casing::Lower);
+    assert_eq!("ırmak-i\u{307}yi\u{307}-ince", lower);
 }
 
 #[test]
-#[cfg(feature = "default_dictionary")]
-fn default_petnames_has_names() {
-    let petnames = Petnames::default();
-    assert_ne!(petnames.names.len(), 0);
+fn generate_cased_with_noun_only_capitalizes_name_only() {
+    let petnames = Petnames::init("Little", "happy", "cat");
+    let name = petnames.generate_cased(&mut StepRng::new(0, 1), 4, "-", Casing::NounOnly);
+    assert_eq!("happy-happy-little-Cat", name);
 }
 
 #[test]
-fn retain_applies_given_predicate() {
-    let petnames_expected = Petnames::init("bob", "bob", "bob jane");
-    let mut petnames = Petnames::init("alice bob carol", "alice bob", "bob carol jane");
-    petnames.retain(|word| word.len() < 5);
-    assert_eq!(petnames_expected, petnames);
+fn generate_proper_capitalizes_names_whatever_the_casing() {
+    let petnames = Petnames::init("BRAVE", "Happily", "london");
+    let mut rng = StepRng::new(0, 1);
+    let name = petnames.generate_proper(&mut rng, 3, "-", Casing::Lower);
+    assert_eq!("happily-brave-London", name);
+    let name = petnames.generate_proper(&mut rng, 3, "-", Casing::Sentence);
+    assert_eq!("Happily-brave-London", name);
+    let petnames = Petnames::init("brave", "happily", "McDonald");
+    let name = petnames.generate_proper(&mut rng, 3, " ", Casing::Title);
+    assert_eq!("Happily Brave McDonald", name);
 }
 
 #[test]
-#[cfg(feature = "default_dictionary")]
-fn default_petnames_has_non_zero_cardinality() {
-    let petnames = Petnames::default();
-    // This test will need to be adjusted when word lists change.
-    assert_eq!(0, petnames.cardinality(0));
-    assert_eq!(456, petnames.cardinality(1));
-    assert_eq!(204744, petnames.cardinality(2));
-    assert_eq!(53438184, petnames.cardinality(3));
-    assert_eq!(13947366024, petnames.cardinality(4));
+fn generate_name_and_slug_correspond() {
+    let petnames = Petnames::init("Little", "happy", "CAT");
+    let (display, slug) = petnames.generate_name_and_slug(&mut StepRng::new(0, 1), 3);
+    assert_eq!("Happy Little Cat", display);
+    assert_eq!(
+        display
+            .to_lowercase()
+            .split(' ')
+            .collect::<Vec<_>>()
+            .join("-"),
+        slug
+    );
 }
 
 #[test]
-fn generate_uses_adverb_adjective_name() {
-    let petnames = Petnames {
-        adjectives: vec!["adjective"],
-        adverbs: vec!["adverb"],
-        names: vec!["name"],
-    };
+fn generate_cow_borrows_single_word_names() {
+    let petnames = Petnames::init("adjective", "adverb", "name");
+    let name = petnames.generate_cow(&mut StepRng::new(0, 1), 1, "-");
+    assert!(matches!(name, Cow::Borrowed("name")));
+}
+
+#[test]
+fn generate_cow_allocates_multiple_word_names() {
+    let petnames = Petnames::init("adjective", "adverb", "name");
+    let name = petnames.generate_cow(&mut StepRng::new(0, 1), 3, "-");
+    assert!(matches!(name, Cow::Owned(ref name) if name == "adverb-adjective-name"));
+}
+
+#[test]
+fn generate_near_length_finds_name_within_tolerance() {
+    let mut rng = StepRng::new(0, 1);
+    let petnames = Petnames::init("aa bb", "", "cc dd");
+    let name = petnames.generate_near_length(&mut rng, 2, "-", 5, 0, DEFAULT_RETRIES);
+    assert_eq!(Some(5), name.map(|name| name.len()));
+    // With no attempts at all, nothing is found.
     assert_eq!(
-        petnames.generate(&mut StepRng::new(0, 1), 3, "-"),
-        "adverb-adjective-name"
+        None,
+        petnames.generate_near_length(&mut rng, 2, "-", 5, 0, 0)
+    );
+}
+
+#[test]
+fn generate_near_length_gives_up_when_unsatisfiable() {
+    let mut rng = StepRng::new(0, 1);
+    let petnames = Petnames::init("aa bb", "", "cc dd");
+    assert_eq!(
+        None,
+        petnames.generate_near_length(&mut rng, 2, "-", 4, 0, DEFAULT_RETRIES)
+    );
+}
+
+#[test]
+fn generate_matching_gives_up_after_retries() {
+    let mut rng = StepRng::new(0, 1);
+    let petnames = Petnames::init("aa bb", "", "cc dd");
+    let mut attempts = 0;
+    let name = petnames.generate_matching(&mut rng, 2, "-", 7, |_| {
+        attempts += 1;
+        false
+    });
+    assert_eq!(None, name);
+    assert_eq!(7, attempts);
+}
+
+#[test]
+fn generate_max_len_with_fallback_tries_separators_in_order() {
+    let mut rng = StepRng::new(0, 1);
+    let petnames = Petnames::init("happy", "very", "cat");
+    let separators = ["--", "_", ""];
+    assert_eq!(
+        Some(("happy--cat".to_string(), "--")),
+        petnames.generate_max_len_with_fallback(&mut rng, 2, &separators, 10, 1)
+    );
+    assert_eq!(
+        Some(("happy_cat".to_string(), "_")),
+        petnames.generate_max_len_with_fallback(&mut rng, 2, &separators, 9, 1)
+    );
+    assert_eq!(
+        Some(("happycat".to_string(), "")),
+        petnames.generate_max_len_with_fallback(&mut rng, 2, &separators, 8, 1)
+    );
+    assert_eq!(
+        None,
+        petnames.generate_max_len_with_fallback(&mut rng, 2, &separators, 7, 1)
+    );
+}
+
+#[test]
+fn estimate_syllables_counts_vowel_groups() {
+    for (syllables, word) in [
+        (0, ""),
+        (1, "cat"),
+        (1, "hmm"),
+        (1, "hare"),
+        (1, "Free"),
+        (2, "eagle"),
+        (2, "happy"),
+        (3, "abalone"),
+        (5, "extraordinary"),
+    ] {
+        assert_eq!(syllables, estimate_syllables(word), "{}", word);
+    }
+}
+
+#[test]
+#[cfg(feature = "std_rng")]
+fn generate_max_syllables_respects_cap() {
+    use rand::{rngs::StdRng, SeedableRng};
+    let mut rng = StdRng::seed_from_u64(5);
+    let petnames = Petnames::init(
+        "extraordinary magnificent red",
+        "",
+        "hippopotamus caterpillar cat",
+    );
+    for _ in 0..100 {
+        let name = petnames
+            .generate_max_syllables(&mut rng, 2, "-", 2, DEFAULT_RETRIES)
+            .unwrap();
+        assert_eq!("red-cat", name);
+    }
+    assert_eq!(
+        None,
+        petnames.generate_max_syllables(&mut rng, 2, "-", 1, DEFAULT_RETRIES)
+    );
+}
+
+#[test]
+#[cfg(feature = "std_rng")]
+fn generate_themed_favours_theme_words() {
+    use rand::{rngs::StdRng, SeedableRng};
+    let mut rng = StdRng::seed_from_u64(11);
+    let petnames = Petnames::init("cosmic happy sad calm", "", "comet cat dog eel");
+    let theme = ["cosmic", "comet", "orbit"];
+    let themed = (0..1000)
+        .flat_map(|_| {
+            let name = petnames.generate_themed(&mut rng, 2, "-", &theme, 0.9);
+            name.split('-').map(String::from).collect::<Vec<_>>()
+        })
+        .filter(|word| theme.contains(&word.as_str()))
+        .count();
+    // Expect 90% + 10% × ¼ ≈ 92.5% of 2000 words to be from the theme.
+    assert!(themed > 1750, "only {} themed words", themed);
+}
+
+#[test]
+fn generate_themed_falls_back_to_whole_list() {
+    let mut rng = StepRng::new(0, 1);
+    let petnames = Petnames::init("happy", "", "cat");
+    assert_eq!(
+        "happy-cat",
+        petnames.generate_themed(&mut rng, 2, "-", &["orbit"], 1.0)
+    );
+}
+
+#[test]
+#[cfg(feature = "std_rng")]
+fn generate_no_adjacent_alliteration_avoids_adjacent_initials() {
+    use rand::{rngs::StdRng, SeedableRng};
+    let mut rng = StdRng::seed_from_u64(3);
+    let petnames = Petnames::init("happy hungry able", "highly oddly", "hen cat");
+    for _ in 0..100 {
+        let name = petnames
+            .generate_no_adjacent_alliteration(&mut rng, 4, "-", 100)
+            .unwrap();
+        let initials: Vec<char> = name.split('-').map(|w| w.chars().next().unwrap()).collect();
+        assert_eq!(4, initials.len());
+        assert!(
+            initials.windows(2).all(|pair| pair[0] != pair[1]),
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn generate_no_adjacent_alliteration_gives_up_when_unsatisfiable() {
+    let mut rng = StepRng::new(0, 1);
+    let petnames = Petnames::init("happy hungry", "", "hen");
+    assert_eq!(
+        None,
+        petnames.generate_no_adjacent_alliteration(&mut rng, 2, "-", 10)
+    );
+}
+
+#[test]
+fn generate_excluding_finds_the_only_name_left() {
+    let mut rng = StepRng::new(0, 1);
+    let petnames = Petnames::init("a1 a2", "", "c1 c2 c3");
+    let exclude: std::collections::HashSet<String> = ["a1-c1", "a1-c2", "a1-c3", "a2-c1", "a2-c3"]
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+    assert_eq!(
+        Some("a2-c2".to_string()),
+        petnames.generate_excluding(&mut rng, 2, "-", |name| exclude.contains(name))
+    );
+}
+
+#[test]
+fn generate_excluding_gives_up_when_all_names_are_excluded() {
+    let mut rng = StepRng::new(0, 1 << 31);
+    let petnames = Petnames::init("a1 a2", "", "c1");
+    let exclude: std::collections::BTreeSet<String> = ["a1-c1", "a2-c1"]
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+    assert_eq!(
+        None,
+        petnames.generate_excluding(&mut rng, 2, "-", |name| exclude.contains(name))
+    );
+}
+
+#[test]
+fn generate_validated_returns_first_name_passing_check() {
+    let mut rng = StepRng::new(0, 1 << 30);
+    let petnames = Petnames::init("", "", "al bob carol dave");
+    let mut checked = Vec::new();
+    let name = petnames.generate_validated(
+        &mut rng,
+        1,
+        "-",
+        |name| {
+            checked.push(name.to_string());
+            name.len() == 5
+        },
+        10,
     );
+    assert_eq!(Some("carol".to_string()), name);
+    assert_eq!(vec!["al", "bob", "carol"], checked);
+}
+
+#[test]
+fn generate_validated_gives_up_after_max_attempts() {
+    let mut rng = StepRng::new(0, 1 << 30);
+    let petnames = Petnames::init("", "", "al bob carol dave");
+    let name = petnames.generate_validated(&mut rng, 1, "-", |name| name.len() == 5, 2);
+    assert_eq!(None, name);
+}
+
+#[test]
+#[cfg(feature = "std_rng")]
+fn distinct_batch_yields_distinct_names_capped_at_cardinality() {
+    use rand::{rngs::StdRng, SeedableRng};
+    let mut rng = StdRng::seed_from_u64(99);
+    let petnames = Petnames::init("a1 a2", "", "c1 c2 c3");
+    let names = petnames.distinct_batch(&mut rng, 2, ".", 100);
+    assert_eq!(6, names.len());
+    let unique: std::collections::HashSet<&String> = names.iter().collect();
+    assert_eq!(6, unique.len());
+    assert_eq!(4, petnames.distinct_batch(&mut rng, 2, ".", 4).len());
+}
+
+#[test]
+#[cfg(feature = "std_rng")]
+fn distinct_batch_yields_every_name_when_n_is_the_cardinality() {
+    use rand::{rngs::StdRng, SeedableRng};
+    let adjectives: Vec<String> = (0..20).map(|i| format!("a{}", i)).collect();
+    let names: Vec<String> = (0..25).map(|i| format!("n{}", i)).collect();
+    let (adjectives, names) = (adjectives.join(" "), names.join(" "));
+    let petnames = Petnames::init(&adjectives, "", &names);
+    assert_eq!(500, petnames.cardinality(2));
+    for seed in 0..20 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let batch = petnames.distinct_batch(&mut rng, 2, ".", 500);
+        let unique: std::collections::HashSet<&String> = batch.iter().collect();
+        assert_eq!(500, unique.len(), "seed {}", seed);
+    }
 }
 
 #[test]
@@ -69,6 +1326,16 @@ fn petname_renders_with_desired_separator() {
     assert_eq!(petname(7, "@").split('@').count(), 7);
 }
 
+#[test]
+#[cfg(all(feature = "std_rng", feature = "default_dictionary"))]
+fn petname_with_is_reproducible_with_seeded_rng() {
+    use rand::{rngs::StdRng, SeedableRng};
+    let name1 = petname_with(&mut StdRng::seed_from_u64(42), 3, "-");
+    let name2 = petname_with(&mut StdRng::seed_from_u64(42), 3, "-");
+    assert_eq!(name1.split('-').count(), 3);
+    assert_eq!(name1, name2);
+}
+
 #[test]
 fn petnames_iter_has_cardinality() {
     let mut rng = StepRng::new(0, 1);
@@ -87,6 +1354,124 @@ fn petnames_iter_yields_names() {
     assert_eq!(Some("bar.foo.baz".to_string()), iter.next());
 }
 
+#[test]
+fn petnames_iter_seeded_offset_shifts_the_same_sequence() {
+    let petnames = Petnames::init("happy sad calm", "very", "cat dog eel");
+    let from_zero: Vec<String> = petnames.iter_seeded_offset(7, 0, 2, "-").take(10).collect();
+    let from_five: Vec<String> = petnames.iter_seeded_offset(7, 5, 2, "-").take(5).collect();
+    assert_ne!(from_zero[0], from_five[0]);
+    assert_eq!(from_zero[5], from_five[0]);
+    assert_eq!(from_zero[5..], from_five[..]);
+    assert_eq!(
+        from_zero,
+        petnames
+            .iter_seeded_offset(7, 0, 2, "-")
+            .take(10)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn petnames_name_at_covers_every_name_once() {
+    let petnames = Petnames::init("happy sad", "very truly", "cat dog eel");
+    let all: std::collections::BTreeSet<String> = petnames
+        .iter_non_repeating(&mut StepRng::new(0, 1), 3, "-")
+        .collect();
+    assert_eq!(12, all.len());
+    for seed in 0..10 {
+        let names: Vec<String> = (0..12)
+            .map(|index| petnames.name_at(seed, index, 3, "-").unwrap())
+            .collect();
+        assert_eq!(
+            all,
+            names
+                .iter()
+                .cloned()
+                .collect::<std::collections::BTreeSet<_>>()
+        );
+        assert_eq!(None, petnames.name_at(seed, 12, 3, "-"));
+    }
+    assert_ne!(
+        (0..12)
+            .map(|index| petnames.name_at(1, index, 3, "-"))
+            .collect::<Vec<_>>(),
+        (0..12)
+            .map(|index| petnames.name_at(2, index, 3, "-"))
+            .collect::<Vec<_>>(),
+    );
+    // Only one name; and no names at all.
+    let petnames = Petnames::init("happy", "very", "cat");
+    assert_eq!(
+        Some("happy-cat".to_string()),
+        petnames.name_at(7, 0, 2, "-")
+    );
+    assert_eq!(None, Petnames::init("", "", "").name_at(7, 0, 2, "-"));
+}
+
+#[test]
+fn petnames_into_iter_owned_can_move_to_another_thread() {
+    let petnames = Petnames::init("happy sad calm", "very", "cat dog eel");
+    let expected: Vec<String> = petnames.iter_seeded_offset(3, 0, 2, "-").take(10).collect();
+    let names = petnames.into_iter_owned(3, 2, "-");
+    assert_eq!(9, names.cardinality());
+    let names = std::thread::spawn(move || names.take(10).collect::<Vec<_>>())
+        .join()
+        .unwrap();
+    assert_eq!(expected, names);
+}
+
+#[test]
+fn petnames_iter_enumerated_counts_from_base() {
+    let mut rng = StepRng::new(0, 1 << 30);
+    let petnames = Petnames::init("", "", "alice bob carol dave");
+    let names: Vec<(usize, String)> = petnames
+        .iter_enumerated(&mut rng, 1, "-", 10)
+        .take(3)
+        .collect();
+    assert_eq!(
+        vec![
+            (10, "alice".to_string()),
+            (11, "bob".to_string()),
+            (12, "carol".to_string())
+        ],
+        names
+    );
+    let names = petnames.iter_enumerated(&mut rng, 1, "-", usize::MAX - 1);
+    assert_eq!(2, names.count());
+}
+
+#[test]
+fn petnames_iter_until_stops_after_first_match() {
+    let mut rng = StepRng::new(0, 1 << 30);
+    let petnames = Petnames::init("", "", "alice bob carol dave");
+    let names: Vec<String> = petnames
+        .iter_until(&mut rng, 1, ".", |name| name.starts_with('c'))
+        .collect();
+    assert_eq!(vec!["alice", "bob", "carol"], names);
+}
+
+#[test]
+#[cfg(feature = "std_rng")]
+fn petnames_iter_variable_words_yields_names_of_varying_length() {
+    use rand::{rngs::StdRng, SeedableRng};
+    let mut rng = StdRng::seed_from_u64(7);
+    let petnames = Petnames::init("a", "b", "c");
+    let lengths: std::collections::BTreeSet<usize> = petnames
+        .iter_variable_words(&mut rng, 1, 3, "-")
+        .take(100)
+        .map(|name| name.split('-').count())
+        .collect();
+    assert_eq!(vec![1, 2, 3], lengths.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+#[should_panic(expected = "words 3 > 2")]
+fn petnames_iter_variable_words_panics_when_min_exceeds_max() {
+    let mut rng = StepRng::new(0, 1);
+    let petnames = Petnames::init("a", "b", "c");
+    let _ = petnames.iter_variable_words(&mut rng, 3, 2, "-");
+}
+
 #[test]
 fn petnames_iter_non_repeating_yields_unique_names() {
     let mut rng = StepRng::new(0, 1);
@@ -94,13 +1479,26 @@ fn petnames_iter_non_repeating_yields_unique_names() {
     let names: Vec<String> = petnames.iter_non_repeating(&mut rng, 3, ".").collect();
     assert_eq!(
         vec![
-            "b2.a2.c2", "b3.a2.c2", "b1.a2.c2", "b2.a1.c2", "b3.a1.c2", "b1.a1.c2", "b2.a2.c1",
-            "b3.a2.c1", "b1.a2.c1", "b2.a1.c1", "b3.a1.c1", "b1.a1.c1"
+            "b3.a2.c2", "b1.a2.c2", "b2.a2.c2", "b3.a1.c2", "b1.a1.c2", "b2.a1.c2", "b3.a2.c1",
+            "b1.a2.c1", "b2.a2.c1", "b3.a1.c1", "b1.a1.c1", "b2.a1.c1"
         ],
         names
     )
 }
 
+#[test]
+#[cfg(feature = "std_rng")]
+fn petnames_iter_non_repeating_is_reproducible_with_seeded_rng() {
+    use rand::{rngs::StdRng, SeedableRng};
+    let petnames = Petnames::init("a1 a2 a3", "b1 b2 b3 b4", "c1 c2 c3 c4 c5");
+    let mut rng1 = StdRng::seed_from_u64(1234);
+    let names1: Vec<String> = petnames.iter_non_repeating(&mut rng1, 3, ".").collect();
+    let mut rng2 = StdRng::seed_from_u64(1234);
+    let names2: Vec<String> = petnames.iter_non_repeating(&mut rng2, 3, ".").collect();
+    assert_eq!(60, names1.len());
+    assert_eq!(names1, names2);
+}
+
 #[test]
 fn petnames_iter_non_repeating_yields_nothing_when_any_word_list_is_empty() {
     let mut rng = StepRng::new(0, 1);
@@ -110,9 +1508,401 @@ fn petnames_iter_non_repeating_yields_nothing_when_any_word_list_is_empty() {
 }
 
 #[test]
-fn petnames_iter_non_repeating_yields_nothing_when_no_word_lists_are_given() {
+fn petnames_iter_non_repeating_yields_one_empty_name_when_no_word_lists_are_given() {
     let mut rng = StepRng::new(0, 1);
     let petnames = Petnames::init("a1 a2", "b1 b2", "c1 c2");
     let names: Vec<String> = petnames.iter_non_repeating(&mut rng, 0, ".").collect();
-    assert_eq!(Vec::<String>::new(), names);
+    assert_eq!(vec![String::new()], names);
+    // This agrees with the cardinality and with `name_at`.
+    assert_eq!(1, petnames.cardinality(0));
+    assert_eq!(Some(String::new()), petnames.name_at(0, 0, 0, "."));
+    assert_eq!(None, petnames.name_at(0, 1, 0, "."));
+}
+
+#[test]
+#[cfg(feature = "std_rng")]
+fn petnames_generate_stem_weighted_flattens_stems() {
+    use rand::{rngs::StdRng, SeedableRng};
+    let petnames = Petnames::init("", "", "cat cats catty catkin dog");
+    let mut rng = StdRng::seed_from_u64(134);
+    let samples = 4000;
+    let uniform = (0..samples)
+        .filter(|_| petnames.generate(&mut rng, 1, "").starts_with("cat"))
+        .count();
+    let weighted = (0..samples)
+        .filter(|_| {
+            petnames
+                .generate_stem_weighted(&mut rng, 1, "", 3)
+                .starts_with("cat")
+        })
+        .count();
+    // Uniformly, 4 in 5 names are "cat…"; weighted by stem, 1 in 2 are.
+    assert!((3000..3400).contains(&uniform), "uniform: {}", uniform);
+    assert!((1800..2200).contains(&weighted), "weighted: {}", weighted);
+}
+
+#[test]
+fn petnames_iter_pairs_yields_different_names() {
+    let mut rng = StepRng::new(0, 1 << 30);
+    let petnames = Petnames::init("a b", "c d", "e f");
+    for (first, second) in petnames.iter_pairs(&mut rng, 2, "-").take(100) {
+        assert_ne!(first, second);
+    }
+}
+
+#[test]
+fn petnames_iter_pairs_ends_when_names_cannot_differ() {
+    let mut rng = StepRng::new(0, 1 << 30);
+    let petnames = Petnames::init("a", "b", "c");
+    assert_eq!(None, petnames.iter_pairs(&mut rng, 2, "-").next());
+}
+
+#[test]
+#[cfg(all(feature = "bloom", feature = "std_rng"))]
+fn petnames_iter_probably_unique_yields_no_duplicates() {
+    use rand::{rngs::StdRng, SeedableRng};
+    use std::collections::HashSet;
+    let petnames = Petnames::init("a b c d e f g h", "i j k l", "m n o p q r s t");
+    let mut rng = StdRng::seed_from_u64(142);
+    let names: Vec<String> = petnames
+        .iter_probably_unique(&mut rng, 3, "-", 256, 0.001)
+        .take(200)
+        .collect();
+    assert_eq!(200, names.len());
+    assert_eq!(200, names.iter().collect::<HashSet<_>>().len());
+}
+
+#[test]
+#[cfg(feature = "bloom")]
+fn petnames_iter_probably_unique_ends_when_names_are_exhausted() {
+    let mut rng = StepRng::new(0, 1 << 30);
+    let petnames = Petnames::init("a b", "c", "d e");
+    let names: Vec<String> = petnames
+        .iter_probably_unique(&mut rng, 2, "-", 16, 0.01)
+        .collect();
+    assert!(names.len() <= 4);
+}
+
+#[test]
+fn name_for_is_stable_for_the_same_key() {
+    let petnames = Petnames::init("a b c d e f g h", "i j k l", "m n o p q r s t");
+    assert_eq!(
+        petnames.name_for("alpha", 3, "-"),
+        petnames.name_for("alpha", 3, "-")
+    );
+    assert_eq!(
+        petnames.name_for(42u64, 3, "-"),
+        petnames.name_for(42u64, 3, "-")
+    );
+    let names: std::collections::HashSet<String> = (0..100u64)
+        .map(|key| petnames.name_for(key, 3, "-"))
+        .collect();
+    // There are 256 possible names; 100 keys should give plenty of them.
+    assert!(names.len() > 50, "only {} distinct names", names.len());
+}
+
+#[test]
+fn name_for_is_pinned() {
+    // Names for keys must never change, whatever version of `rand` is used.
+    let petnames = Petnames::init("a b c d e f g h", "i j k l", "m n o p q r s t");
+    assert_eq!("k-b-r", petnames.name_for("alpha", 3, "-"));
+    assert_eq!("i-a-t", petnames.name_for("beta", 3, "-"));
+}
+
+#[test]
+fn generate_ident_makes_keywords_valid() {
+    let petnames = Petnames::init("", "", "match");
+    let ident = petnames.generate_ident(&mut StepRng::new(0, 1), 1);
+    assert_eq!("match_", ident);
+    assert!(is_valid_ident(&ident));
+}
+
+#[test]
+fn generate_ident_strips_invalid_characters() {
+    let mut rng = StepRng::new(0, 1);
+    let petnames = Petnames::init("Well-Known", "", "3D");
+    assert_eq!("wellknown_3d", petnames.generate_ident(&mut rng, 2));
+    assert_eq!("_3d", petnames.generate_ident(&mut rng, 1));
+    let petnames = Petnames::init("!!", "", "_");
+    assert_eq!("unnamed_", petnames.generate_ident(&mut rng, 2));
+    assert!(is_valid_ident("unnamed_"));
+}
+
+#[test]
+fn is_valid_ident_rejects_invalid_identifiers() {
+    for ident in ["", "_", "fn", "Self", "9lives", "happy-cat", "naïve"] {
+        assert!(!is_valid_ident(ident), "{:?} should be invalid", ident);
+    }
+    for ident in ["happy_cat", "_9lives", "fn_", "r2d2"] {
+        assert!(is_valid_ident(ident), "{:?} should be valid", ident);
+    }
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "default_dictionary"))]
+fn large_shared_is_shared() {
+    let petnames = Petnames::large_shared();
+    assert!(std::ptr::eq(petnames, Petnames::large_shared()));
+    assert_eq!(&Petnames::large(), petnames);
+    let handle = std::thread::spawn(|| Petnames::large_shared() as *const _ as usize);
+    assert_eq!(petnames as *const _ as usize, handle.join().unwrap());
+    let name = petnames.generate(&mut StepRng::new(0, 1), 3, "-");
+    assert_eq!(3, name.split('-').count());
+}
+
+#[test]
+#[cfg(feature = "std_rng")]
+fn sample_stats_are_consistent() {
+    use rand::{rngs::StdRng, SeedableRng};
+    let mut rng = StdRng::seed_from_u64(9);
+    let petnames = Petnames::init("big happy", "very", "ox cat elephant");
+    let stats = petnames.sample_stats(&mut rng, 2, "-", 200);
+    assert_eq!(200, stats.samples);
+    assert!(stats.min_len as f64 <= stats.mean_len);
+    assert!(stats.mean_len <= stats.max_len as f64);
+    assert!(stats.unique <= stats.samples);
+    // "big-ox" and "happy-elephant", and all 6 names, are near certain.
+    assert_eq!((6, 14, 6), (stats.min_len, stats.max_len, stats.unique));
+}
+
+#[test]
+fn sample_stats_of_nothing_are_zero() {
+    let petnames = Petnames::init("happy", "very", "cat");
+    let stats = petnames.sample_stats(&mut StepRng::new(0, 1), 2, "-", 0);
+    assert_eq!(
+        (0, 0, 0, 0),
+        (stats.samples, stats.min_len, stats.max_len, stats.unique)
+    );
+    assert_eq!(0.0, stats.mean_len);
+    let stats = petnames.sample_stats(&mut StepRng::new(0, 1), 2, "-", 3);
+    assert_eq!(
+        (3, 9, 9, 1),
+        (stats.samples, stats.min_len, stats.max_len, stats.unique)
+    );
+    assert_eq!(9.0, stats.mean_len);
+}
+
+#[test]
+#[cfg(feature = "default_dictionary")]
+fn cardinality_for_grows_with_complexity() {
+    let complexities: Vec<Complexity> = Complexity::all().collect();
+    assert_eq!(&Complexity::ALL[..], &complexities[..]);
+    let cardinalities: Vec<u128> = Complexity::all()
+        .map(|complexity| Petnames::cardinality_for(complexity, 3))
+        .collect();
+    assert_eq!(Petnames::small().cardinality(3), cardinalities[0]);
+    assert!(cardinalities[0] <= cardinalities[1]);
+    assert!(cardinalities[1] <= cardinalities[2]);
+}
+
+#[test]
+#[cfg(feature = "default_dictionary")]
+fn iter_all_complexities_yields_one_name_for_each_complexity() {
+    let mut rng = StepRng::new(0, 1);
+    let names: Vec<(Complexity, String)> = iter_all_complexities(&mut rng, 2, "-").collect();
+    assert_eq!(
+        vec![Complexity::Small, Complexity::Medium, Complexity::Large],
+        names
+            .iter()
+            .map(|(complexity, _)| *complexity)
+            .collect::<Vec<_>>()
+    );
+    for (complexity, name) in names {
+        let expected =
+            Petnames::with_complexity(complexity).generate(&mut StepRng::new(0, 1), 2, "-");
+        assert_eq!(expected, name);
+    }
+}
+
+#[test]
+fn generate_with_checksum_verifies() {
+    let petnames = Petnames::init("able bad calm", "very", "ant bee cat");
+    let mut rng = StepRng::new(0, 1 << 30);
+    for _ in 0..9 {
+        let name = petnames.generate_with_checksum(&mut rng, 3, "-");
+        assert!(verify_checksum(&name), "{:?} should verify", name);
+    }
+}
+
+#[test]
+fn verify_checksum_detects_single_letter_typos() {
+    let name = Petnames::init("able", "very", "ant").generate_with_checksum(
+        &mut StepRng::new(0, 1),
+        3,
+        "-",
+    );
+    assert!(verify_checksum(&name));
+    for (index, c) in name.char_indices().filter(|(_, c)| c.is_ascii_lowercase()) {
+        for typo in ('a'..='z').filter(|typo| *typo != c) {
+            let mut corrupted = name.clone();
+            corrupted.replace_range(index..index + 1, &typo.to_string());
+            assert!(
+                !verify_checksum(&corrupted),
+                "{:?} should not verify",
+                corrupted
+            );
+        }
+    }
+    assert!(!verify_checksum(""));
+}
+
+#[test]
+#[cfg(feature = "std_rng")]
+fn generate_variable_separator_repeats_separator_within_bounds() {
+    use rand::{rngs::StdRng, SeedableRng};
+    let petnames = Petnames::init("able", "very", "ant");
+    let mut rng = StdRng::seed_from_u64(155);
+    let mut repetitions = std::collections::BTreeSet::new();
+    for _ in 0..100 {
+        let name = petnames.generate_variable_separator(&mut rng, 4, ".", 1, 3);
+        let gaps: Vec<&str> = name
+            .split(|c: char| c.is_alphabetic())
+            .filter(|gap| !gap.is_empty())
+            .collect();
+        assert_eq!(3, gaps.len(), "{:?}", name);
+        for gap in gaps {
+            assert!((1..=3).contains(&gap.len()), "{:?}", name);
+            repetitions.insert(gap.len());
+        }
+    }
+    assert_eq!(3, repetitions.len());
+}
+
+#[test]
+#[should_panic(expected = "separator repetitions 2 > 1")]
+fn generate_variable_separator_panics_when_min_exceeds_max() {
+    let petnames = Petnames::init("able", "very", "ant");
+    petnames.generate_variable_separator(&mut StepRng::new(0, 1), 2, ".", 2, 1);
+}
+
+#[test]
+#[cfg(feature = "std_rng")]
+fn non_repeating_names_reshuffle_keeps_names_unique_and_complete() {
+    use rand::{rngs::StdRng, SeedableRng};
+    use std::collections::HashSet;
+    let petnames = Petnames::init("a1 a2 a3", "b1 b2 b3 b4", "c1 c2 c3 c4 c5");
+    let all: HashSet<String> = petnames
+        .iter_non_repeating(&mut StepRng::new(0, 1), 3, ".")
+        .collect();
+    assert_eq!(60, all.len());
+    for seed in 0..20 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut names = petnames.iter_non_repeating(&mut rng, 3, ".");
+        let mut seen = Vec::new();
+        // Reshuffle at assorted points, including before the first name.
+        for take in [0, 1, 3, 4, 7, 12, 13] {
+            seen.extend(names.by_ref().take(take));
+            names.reshuffle(&mut rng);
+        }
+        seen.extend(names.by_ref());
+        names.reshuffle(&mut rng);
+        assert_eq!(None, names.next());
+        assert_eq!(60, seen.len(), "seed {}", seed);
+        assert_eq!(
+            all,
+            seen.into_iter().collect::<HashSet<_>>(),
+            "seed {}",
+            seed
+        );
+    }
+}
+
+#[test]
+fn word_count_maps_to_upstream_structure() {
+    // Upstream: 1 word is a name, 2 words are an adjective and a name, and
+    // each word beyond that is an adverb at the front.
+    use WordKind::*;
+    let petnames = Petnames::init("adjective", "adverb", "name");
+    let mut rng = StepRng::new(0, 1);
+    let kinds = |words| -> Vec<WordKind> {
+        petnames
+            .generate_tagged(&mut StepRng::new(0, 1), words)
+            .into_iter()
+            .map(|(kind, _)| kind)
+            .collect()
+    };
+    assert_eq!(Vec::<WordKind>::new(), kinds(0));
+    assert_eq!(vec![Name], kinds(1));
+    assert_eq!(vec![Adjective, Name], kinds(2));
+    assert_eq!(vec![Adverb, Adjective, Name], kinds(3));
+    assert_eq!(vec![Adverb, Adverb, Adverb, Adjective, Name], kinds(5));
+    assert_eq!(
+        "adverb-adverb-adjective-name",
+        petnames.generate(&mut rng, 4, "-")
+    );
+}
+
+#[test]
+#[cfg(feature = "std_rng")]
+fn petnames_iter_balanced_uses_names_evenly() {
+    use rand::{rngs::StdRng, SeedableRng};
+    use std::collections::HashMap;
+    let petnames = Petnames::init(
+        "a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16",
+        "b1 b2 b3 b4",
+        "n1 n2 n3 n4 n5",
+    );
+    let spread = |names: Vec<String>| {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for name in names {
+            let noun = name.rsplit('-').next().unwrap().to_string();
+            *counts.entry(noun).or_insert(0) += 1;
+        }
+        assert_eq!(5, counts.len());
+        counts.values().max().unwrap() - counts.values().min().unwrap()
+    };
+    let mut rng = StdRng::seed_from_u64(159);
+    let balanced = spread(petnames.iter_balanced(&mut rng, 3, "-").take(52).collect());
+    let plain = spread(petnames.iter(&mut rng, 3, "-").take(52).collect());
+    // 52 names over 5 nouns: 10 or 11 of each when balanced.
+    assert_eq!(1, balanced);
+    assert!(plain > balanced, "plain spread {}", plain);
+}
+
+#[test]
+fn name_from_indices_rebuilds_generated_names() {
+    let petnames = Petnames::init("a1 a2 a3", "b1 b2", "c1 c2 c3 c4");
+    let mut rng = StepRng::new(0, 1 << 29);
+    for words in 1..5 {
+        let indices = petnames.generate_indices(&mut rng, words);
+        assert_eq!(words as usize, indices.len());
+        let name = petnames.name_from_indices(&indices, "-").unwrap();
+        assert!(petnames.matches_shape(&name, words, "-"), "{:?}", name);
+    }
+}
+
+#[cfg(all(feature = "tracing", feature = "std"))]
+#[test]
+fn petnames_generate_emits_tracing_event() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tracing::{span, Event, Metadata, Subscriber};
+
+    struct CountEvents(Arc<AtomicUsize>);
+
+    impl Subscriber for CountEvents {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+        fn new_span(&self, _: &span::Attributes<'_>) -> span::Id {
+            span::Id::from_u64(1)
+        }
+        fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+        fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+        fn event(&self, event: &Event<'_>) {
+            if event.metadata().target() == "petname" {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+        fn enter(&self, _: &span::Id) {}
+        fn exit(&self, _: &span::Id) {}
+    }
+
+    let events = Arc::new(AtomicUsize::new(0));
+    let petnames = Petnames::init("happy", "very", "cat");
+    let mut rng = StepRng::new(0, 1);
+    tracing::subscriber::with_default(CountEvents(Arc::clone(&events)), || {
+        petnames.generate(&mut rng, 2, "-");
+    });
+    assert_eq!(1, events.load(Ordering::SeqCst));
 }
@@ -0,0 +1,657 @@
+#![cfg(all(feature = "clap", feature = "std_rng", feature = "default_dictionary"))]
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+/// Create a fresh directory containing the given word lists.
+fn dictionary(name: &str, adjectives: &str, adverbs: &str, names: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("petname-test-{}-{}", std::process::id(), name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("adjectives.txt"), adjectives).unwrap();
+    fs::write(dir.join("adverbs.txt"), adverbs).unwrap();
+    fs::write(dir.join("names.txt"), names).unwrap();
+    dir
+}
+
+fn petname(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_petname"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn stats_reports_first_letter_counts() {
+    let dir = dictionary("stats", "able apt bad", "very", "ant bee bug cat");
+    let output = petname(&["--dir", dir.to_str().unwrap(), "--stats"]);
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("adjectives: a=2 b=1\n"));
+    assert!(stderr.contains("adverbs: v=1\n"));
+    assert!(stderr.contains("names: a=1 b=2 c=1\n"));
+}
+
+#[test]
+fn missing_word_lists_are_all_reported() {
+    let dir = dictionary("missing", "able", "very", "ant");
+    fs::remove_file(dir.join("adverbs.txt")).unwrap();
+    fs::remove_file(dir.join("names.txt")).unwrap();
+    let output = petname(&["--dir", dir.to_str().unwrap()]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains(dir.join("adverbs.txt").to_str().unwrap()));
+    assert!(stderr.contains(dir.join("names.txt").to_str().unwrap()));
+    assert!(!stderr.contains(dir.join("adjectives.txt").to_str().unwrap()));
+}
+
+#[test]
+fn length_gives_up_after_retries() {
+    let dir = dictionary("retries", "able", "very", "ant");
+    let output = petname(&[
+        "--dir",
+        dir.to_str().unwrap(),
+        "--length",
+        "20",
+        "--retries",
+        "1",
+    ]);
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("gave up after 1 attempts: no petname of length 20±0 found"));
+}
+
+#[test]
+fn length_generates_names_within_tolerance() {
+    let dir = dictionary("length", "able", "very", "ant");
+    let output = petname(&[
+        "--dir",
+        dir.to_str().unwrap(),
+        "--length",
+        "8",
+        "--tolerance",
+        "1",
+    ]);
+    assert!(output.status.success());
+    assert_eq!("able-ant\n", String::from_utf8(output.stdout).unwrap());
+}
+
+#[test]
+fn stream_with_count_stops_at_count() {
+    let output = petname(&["--stream", "--count", "3"]);
+    assert!(output.status.success());
+    assert_eq!(3, String::from_utf8(output.stdout).unwrap().lines().count());
+}
+
+#[test]
+fn count_defaults_to_one() {
+    let output = petname(&[]);
+    assert!(output.status.success());
+    assert_eq!(1, String::from_utf8(output.stdout).unwrap().lines().count());
+}
+
+#[test]
+fn separator_in_custom_words_is_warned_about() {
+    let dir = dictionary("separator", "well-known", "very", "cat");
+    let output = petname(&["--dir", dir.to_str().unwrap(), "--separator", "-"]);
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Warning: some words contain the separator \"-\""));
+    let output = petname(&["--dir", dir.to_str().unwrap(), "--separator", "_"]);
+    assert!(output.status.success());
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn separator_in_custom_words_is_an_error_when_strict() {
+    let dir = dictionary("separator-strict", "well-known", "very", "cat");
+    let output = petname(&[
+        "--dir",
+        dir.to_str().unwrap(),
+        "--separator",
+        "-",
+        "--strict",
+    ]);
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("invalid separator: some words contain the separator \"-\""));
+}
+
+#[test]
+fn empty_separator_with_non_repeating_is_warned_about() {
+    let args = ["--separator", "", "--non-repeating"];
+    let output = petname(&args);
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Warning: the separator is empty"));
+    let output = petname(&[&args[..], &["--strict"]].concat());
+    assert!(!output.status.success());
+}
+
+#[test]
+fn template_is_filled_and_keeps_literal_text() {
+    let dir = dictionary("template", "happy", "very", "cat");
+    let output = petname(&[
+        "--dir",
+        dir.to_str().unwrap(),
+        "--template",
+        "the {adjective} {name} who runs {adverb} {{fast}}",
+        "--count",
+        "2",
+    ]);
+    assert!(output.status.success());
+    assert_eq!(
+        "the happy cat who runs very {fast}\nthe happy cat who runs very {fast}\n",
+        String::from_utf8(output.stdout).unwrap()
+    );
+}
+
+#[test]
+fn template_with_unknown_placeholder_is_an_error() {
+    let output = petname(&["--template", "the {colour} {name}"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("invalid template: unknown placeholder {colour}"));
+}
+
+#[test]
+fn template_conflicts_with_words_and_separator() {
+    for args in [["--words", "3"], ["--separator", "_"]] {
+        let output = petname(&[&args[..], &["--template", "{name}"]].concat());
+        assert!(!output.status.success());
+    }
+}
+
+#[test]
+fn list_words_prints_filtered_category() {
+    let dir = dictionary("list", "able apt bad", "very", "ant bee bug cat");
+    let output = petname(&["--dir", dir.to_str().unwrap(), "--list-words", "names"]);
+    assert!(output.status.success());
+    assert_eq!(
+        "ant\nbee\nbug\ncat\n",
+        String::from_utf8(output.stdout).unwrap()
+    );
+    let output = petname(&[
+        "--dir",
+        dir.to_str().unwrap(),
+        "--list-words",
+        "adjectives",
+        "-l",
+        "3",
+    ]);
+    assert!(output.status.success());
+    assert_eq!("apt\nbad\n", String::from_utf8(output.stdout).unwrap());
+}
+
+#[test]
+fn list_words_applies_alliteration_and_initials() {
+    let dir = dictionary("list-filters", "able bad cute", "barely", "ant bee cat");
+    let args = ["--dir", dir.to_str().unwrap(), "--list-words", "names"];
+    let output = petname(&[&args[..], &["--alliterate-with", "b"]].concat());
+    assert!(output.status.success());
+    assert_eq!("bee\n", String::from_utf8(output.stdout).unwrap());
+    let output = petname(&[&args[..], &["--initials", "ac"]].concat());
+    assert!(output.status.success());
+    assert_eq!("ant\ncat\n", String::from_utf8(output.stdout).unwrap());
+}
+
+#[test]
+fn list_words_writes_to_output_file() {
+    let dir = dictionary("list-output", "able", "very", "ant bee");
+    let file = dir.join("words.out");
+    let output = petname(&[
+        "--dir",
+        dir.to_str().unwrap(),
+        "--list-words",
+        "names",
+        "--output",
+        file.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    assert_eq!("ant\nbee\n", fs::read_to_string(file).unwrap());
+}
+
+#[test]
+fn benchmark_reports_rate_without_printing_names() {
+    let output = petname(&["--benchmark", "1000", "--complexity", "2"]);
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("benchmark: generated 1000 names in "));
+    assert!(stderr.contains(" names/sec)\n"));
+}
+
+#[test]
+fn benchmark_uses_the_same_names_as_output() {
+    let dir = dictionary("benchmark-options", "able bad", "very", "ant bee");
+    let args = ["--dir", dir.to_str().unwrap(), "--benchmark", "10"];
+    let output = petname(&[&args[..], &["--non-repeating"]].concat());
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("benchmark: generated 4 names in "));
+    let output = petname(&[&args[..], &["--length", "20", "--retries", "3"]].concat());
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("no petname of length 20"));
+}
+
+#[test]
+fn letters_zero_is_deprecated() {
+    let output = petname(&["--letters", "0"]);
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("--letters=0"));
+    assert!(stderr.contains("specify --no-letter-limit instead."));
+}
+
+#[test]
+fn no_letter_limit_is_not_deprecated() {
+    let output = petname(&["--no-letter-limit"]);
+    assert!(output.status.success());
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn seed_from_environment_is_reproducible() {
+    let run = || {
+        Command::new(env!("CARGO_BIN_EXE_petname"))
+            .args(["--count", "5", "--complexity", "2"])
+            .env("PETNAME_SEED", "139")
+            .output()
+            .unwrap()
+    };
+    let (first, second) = (run(), run());
+    assert!(first.status.success());
+    assert!(!first.stdout.is_empty());
+    assert_eq!(first.stdout, second.stdout);
+}
+
+#[test]
+fn seed_option_overrides_environment() {
+    let run = |env_seed| {
+        Command::new(env!("CARGO_BIN_EXE_petname"))
+            .args(["--count", "5", "--complexity", "2", "--seed", "1"])
+            .env("PETNAME_SEED", env_seed)
+            .output()
+            .unwrap()
+    };
+    assert_eq!(run("2").stdout, run("3").stdout);
+}
+
+#[test]
+fn seed_from_environment_must_be_a_number() {
+    let output = Command::new(env!("CARGO_BIN_EXE_petname"))
+        .env("PETNAME_SEED", "forty-two")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("PETNAME_SEED is not a number"));
+}
+
+#[test]
+fn zero_words_produces_empty_names() {
+    let output = petname(&["--words", "0", "--count", "3"]);
+    assert!(output.status.success());
+    assert!(output.stderr.is_empty());
+    assert_eq!("\n\n\n", String::from_utf8(output.stdout).unwrap());
+}
+
+#[test]
+fn zero_words_non_repeating_produces_one_empty_name() {
+    let output = petname(&["--words", "0", "--non-repeating", "--count", "3"]);
+    assert!(output.status.success());
+    assert_eq!("\n", String::from_utf8(output.stdout).unwrap());
+}
+
+#[test]
+fn emoji_follows_each_name() {
+    let dir = dictionary("emoji", "able", "very", "ant");
+    let output = petname(&["--dir", dir.to_str().unwrap(), "--emoji", "--count", "3"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(3, stdout.lines().count());
+    for line in stdout.lines() {
+        let emoji = line.strip_prefix("able-ant").unwrap();
+        assert_eq!(1, emoji.chars().count());
+    }
+}
+
+#[test]
+fn initials_limits_first_letters() {
+    let dir = dictionary("initials", "able bad", "very", "Ant cat");
+    let output = petname(&["--dir", dir.to_str().unwrap(), "--initials", "ab"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(["able-Ant\n", "bad-Ant\n"].contains(&stdout.as_str()));
+}
+
+#[test]
+fn letters_per_category_filters_independently() {
+    let dir = dictionary(
+        "category-letters",
+        "ox big bigger",
+        "very",
+        "ox cat elephant",
+    );
+    let output = petname(&[
+        "--dir",
+        dir.to_str().unwrap(),
+        "--adj-letters",
+        "2-3",
+        "--name-letters",
+        "4-10",
+        "--list-words",
+        "names",
+    ]);
+    assert!(output.status.success());
+    assert_eq!("elephant\n", String::from_utf8(output.stdout).unwrap());
+    let output = petname(&[
+        "--dir",
+        dir.to_str().unwrap(),
+        "--adj-letters",
+        "2-3",
+        "--name-letters",
+        "4-10",
+        "--list-words",
+        "adjectives",
+    ]);
+    assert_eq!("ox\nbig\n", String::from_utf8(output.stdout).unwrap());
+    let output = petname(&["--name-letters", "5-4"]);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn min_cardinality_fails_when_too_few_names() {
+    let dir = dictionary("min-cardinality", "able bad", "very", "ant");
+    let output = petname(&["--dir", dir.to_str().unwrap(), "--min-cardinality", "3"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("only 2 possible names of 2 words; at least 3 required"));
+    let output = petname(&["--dir", dir.to_str().unwrap(), "--min-cardinality", "2"]);
+    assert!(output.status.success());
+}
+
+#[test]
+fn separator_list_applies_to_each_gap() {
+    let dir = dictionary("separators", "able", "very", "ant");
+    let output = petname(&[
+        "--dir",
+        dir.to_str().unwrap(),
+        "--words",
+        "3",
+        "--separators",
+        " ",
+        "--separators",
+        "-",
+    ]);
+    assert!(output.status.success());
+    assert_eq!("very able-ant\n", String::from_utf8(output.stdout).unwrap());
+}
+
+#[test]
+fn separator_with_comma_is_taken_literally() {
+    let dir = dictionary("separator-comma", "able", "very", "ant");
+    let output = petname(&["--dir", dir.to_str().unwrap(), "-s", ", "]);
+    assert!(output.status.success());
+    assert_eq!("able, ant\n", String::from_utf8(output.stdout).unwrap());
+    let output = petname(&["--dir", dir.to_str().unwrap(), "--separator", ","]);
+    assert_eq!("able,ant\n", String::from_utf8(output.stdout).unwrap());
+}
+
+#[test]
+fn key_always_gives_the_same_name() {
+    let first = petname(&["--key", "my-server-id", "--words", "3"]);
+    let second = petname(&["--key", "my-server-id", "--words", "3"]);
+    assert!(first.status.success());
+    assert_eq!(
+        1,
+        String::from_utf8(first.stdout.clone())
+            .unwrap()
+            .lines()
+            .count()
+    );
+    assert_eq!(first.stdout, second.stdout);
+    let seeded = petname(&["--key", "my-server-id", "--words", "3", "--seed", "149"]);
+    assert_eq!(first.stdout, seeded.stdout);
+}
+
+#[test]
+fn key_writes_to_output_file() {
+    let dir = dictionary("key-output", "able", "very", "ant");
+    let file = dir.join("key.out");
+    let args = ["--dir", dir.to_str().unwrap(), "--key", "my-server-id"];
+    let output = petname(&[&args[..], &["--output", file.to_str().unwrap()]].concat());
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    assert_eq!("able-ant\n", fs::read_to_string(file).unwrap());
+}
+
+#[test]
+fn key_gives_only_one_name() {
+    let output = petname(&["--key", "my-server-id", "--count", "2"]);
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn separator_escapes_are_interpreted() {
+    let dir = dictionary("escapes", "able", "very", "ant");
+    let dir = dir.to_str().unwrap();
+    let separated = |separator| {
+        let output = petname(&["--dir", dir, "--separator", separator]);
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    };
+    assert_eq!("able\tant\n", separated("\\t"));
+    assert_eq!("able\nant\n", separated("\\n"));
+    assert_eq!("able\\tant\n", separated("\\\\t"));
+    assert_eq!("able\\xant\n", separated("\\x"));
+    assert_eq!("able\\ant\n", separated("\\"));
+}
+
+#[test]
+fn compat_defaults_to_medium_words() {
+    let compat = petname(&["--compat", "--seed", "158", "--count", "10"]);
+    let medium = petname(&["--complexity", "1", "--seed", "158", "--count", "10"]);
+    let small = petname(&["--seed", "158", "--count", "10"]);
+    assert!(compat.status.success());
+    assert_eq!(medium.stdout, compat.stdout);
+    assert_ne!(small.stdout, compat.stdout);
+    let explicit = petname(&[
+        "--compat",
+        "--complexity",
+        "2",
+        "--seed",
+        "158",
+        "--count",
+        "10",
+    ]);
+    let large = petname(&["--complexity", "2", "--seed", "158", "--count", "10"]);
+    assert_eq!(large.stdout, explicit.stdout);
+}
+
+#[test]
+fn compat_names_have_upstream_structure() {
+    for (words, expected) in [(1, 1), (2, 2), (3, 3), (5, 5)] {
+        let words = words.to_string();
+        let output = petname(&["--compat", "--words", &words, "--separator", "_"]);
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert_eq!(expected, stdout.trim_end().split('_').count());
+    }
+}
+
+#[test]
+fn dump_config_reports_effective_settings() {
+    let dir = dictionary("dump-config", "bad cut calm", "so", "ant bee");
+    let output = petname(&[
+        "--dir",
+        dir.to_str().unwrap(),
+        "--words",
+        "3",
+        "--separator",
+        "_",
+        "--letters",
+        "3",
+        "--ascii-only",
+        "--dump-config",
+    ]);
+    assert!(output.status.success());
+    assert_eq!(1, String::from_utf8(output.stdout).unwrap().lines().count());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("config: words = 3\n"));
+    assert!(stderr.contains("config: separators = [\"_\"]\n"));
+    assert!(stderr.contains("config: adverb-policy = Linear\n"));
+    assert!(stderr.contains(&format!(
+        "config: dictionary = directory {}\n",
+        dir.display()
+    )));
+    assert!(stderr.contains("config: filters = letters<=3, ascii-only\n"));
+    assert!(stderr.contains("config: cardinality = 4\n"));
+}
+
+#[test]
+fn words_range_varies_word_count() {
+    let dir = dictionary("words-range", "able", "very", "ant");
+    let output = petname(&[
+        "--dir",
+        dir.to_str().unwrap(),
+        "--words",
+        "1-3",
+        "--count",
+        "100",
+    ]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let mut counts: Vec<usize> = stdout.lines().map(|name| name.split('-').count()).collect();
+    counts.sort_unstable();
+    counts.dedup();
+    assert_eq!(vec![1, 2, 3], counts);
+}
+
+#[test]
+fn words_range_must_not_be_reversed() {
+    let output = petname(&["--words", "4-2"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("4 is greater than 2"));
+}
+
+#[test]
+fn output_writes_names_to_file() {
+    let dir = dictionary("output", "able", "very", "ant");
+    let file = dir.join("names.out");
+    let output = petname(&[
+        "--dir",
+        dir.to_str().unwrap(),
+        "--count",
+        "3",
+        "--output",
+        file.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    assert_eq!(
+        "able-ant\nable-ant\nable-ant\n",
+        fs::read_to_string(file).unwrap()
+    );
+}
+
+#[test]
+fn output_reports_unwritable_file() {
+    let dir = dictionary("output-error", "able", "very", "ant");
+    let file = dir.join("missing").join("names.out");
+    let output = petname(&["--output", file.to_str().unwrap()]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains(&format!("cannot write to {}", file.display())));
+}
+
+#[test]
+fn length_histogram_reports_word_lengths() {
+    let dir = dictionary("length-histogram", "able bad", "very", "ant bee");
+    let output = petname(&["--dir", dir.to_str().unwrap(), "--length-histogram"]);
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("length 3: 3\nlength 4: 2\n"));
+}
+
+#[test]
+#[cfg(all(unix, feature = "ctrlc"))]
+fn stream_stops_cleanly_on_interrupt() {
+    use std::io::{BufRead, BufReader, Read};
+    use std::process::Stdio;
+    let mut child = Command::new(env!("CARGO_BIN_EXE_petname"))
+        .arg("--stream")
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+    // Once names are flowing the handler is in place.
+    let mut first = String::new();
+    stdout.read_line(&mut first).unwrap();
+    let killed = Command::new("kill")
+        .args(["-INT", &child.id().to_string()])
+        .status()
+        .unwrap();
+    assert!(killed.success());
+    let mut rest = String::new();
+    stdout.read_to_string(&mut rest).unwrap();
+    assert!(child.wait().unwrap().success());
+    assert!(rest.is_empty() || rest.ends_with('\n'));
+}
+
+#[test]
+#[cfg(feature = "blocklist")]
+fn safe_removes_planted_profanity() {
+    let dir = dictionary("safe", "happy crap", "very", "cat");
+    let args = ["--dir", dir.to_str().unwrap(), "--list-words", "adjectives"];
+    let output = petname(&args);
+    assert_eq!("happy\ncrap\n", String::from_utf8(output.stdout).unwrap());
+    let output = petname(&[&args[..], &["--safe"]].concat());
+    assert!(output.status.success());
+    assert_eq!("happy\n", String::from_utf8(output.stdout).unwrap());
+}
+
+#[test]
+fn repeat_prints_each_name_several_times() {
+    let dir = dictionary("repeat", "able bad", "very", "ant");
+    let output = petname(&[
+        "--dir",
+        dir.to_str().unwrap(),
+        "--count",
+        "4",
+        "--repeat",
+        "3",
+        "--separator",
+        "+",
+        "--number",
+    ]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(12, lines.len());
+    for (ordinal, copies) in (1..).zip(lines.chunks(3)) {
+        assert!(copies.iter().all(|&line| line == copies[0]));
+        let name = format!("{}: ", ordinal);
+        assert!(copies[0].starts_with(&name), "{}", copies[0]);
+        assert!(copies[0].ends_with("+ant"), "{}", copies[0]);
+    }
+    let output = petname(&["--repeat", "0"]);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn number_prefixes_names_with_ordinals() {
+    let dir = dictionary("number", "able", "very", "ant");
+    let output = petname(&["--dir", dir.to_str().unwrap(), "--count", "3", "--number"]);
+    assert!(output.status.success());
+    assert_eq!(
+        "1: able-ant\n2: able-ant\n3: able-ant\n",
+        String::from_utf8(output.stdout).unwrap()
+    );
+}